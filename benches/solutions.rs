@@ -0,0 +1,177 @@
+// benches/solutions.rs
+//! `cargo bench` suite: parsing and each part of every registered day,
+//! benchmarked separately on the real input.
+//!
+//! Each benchmark warms up, then takes a series of samples (each averaging
+//! enough iterations to be measurable) and reports the mean with a 95%
+//! confidence interval. Results are saved under `target/aoc-bench/` and the
+//! next run reports the change against them, so an optimisation can be judged
+//! by whether the intervals still overlap.
+//!
+//! Pass filters to run a subset: `cargo bench -- 2024/day06` or `part2`.
+//! Days without an input file are skipped.
+
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use advent_of_code::bench::{format_duration, Stats};
+use advent_of_code::input::{self, FetchPolicy, InputSource};
+use advent_of_code::solutions;
+
+const WARM_UP: Duration = Duration::from_millis(500);
+/// Target time spent sampling each benchmark; `AOC_BENCH_SECS` overrides it.
+const MEASUREMENT: Duration = Duration::from_secs(3);
+const SAMPLES: usize = 50;
+/// Slow steps take fewer samples rather than blowing the time budget, but
+/// never fewer than this.
+const MIN_SAMPLES: usize = 10;
+
+/// Mean time per iteration with the half-width of its 95% confidence interval.
+#[derive(Debug, Clone, Copy)]
+struct Estimate {
+    mean: Duration,
+    margin: Duration,
+}
+
+impl Estimate {
+    fn from_samples(samples: &[Duration]) -> Estimate {
+        let stats = Stats::from_samples(samples);
+        let margin = stats.stddev.as_secs_f64() * 1.96 / (samples.len() as f64).sqrt();
+        Estimate { mean: stats.mean, margin: Duration::from_secs_f64(margin) }
+    }
+
+    fn low(&self) -> Duration {
+        self.mean.saturating_sub(self.margin)
+    }
+
+    fn high(&self) -> Duration {
+        self.mean + self.margin
+    }
+}
+
+fn main() {
+    // cargo passes `--bench`; anything else is a name filter
+    let filters: Vec<String> = env::args().skip(1).filter(|arg| !arg.starts_with('-')).collect();
+    let measurement = env::var("AOC_BENCH_SECS")
+        .ok()
+        .and_then(|secs| secs.parse::<f64>().ok())
+        .filter(|secs| *secs > 0.0)
+        .map_or(MEASUREMENT, Duration::from_secs_f64);
+    let selected = |name: &str| filters.is_empty() || filters.iter().any(|filter| name.contains(filter.as_str()));
+
+    for calendar in solutions::calendars() {
+        for solution in calendar.all() {
+            let prefix = format!("{}/day{:02}", calendar.year(), solution.day());
+            if !["parse", "part1", "part2"].iter().any(|step| selected(&format!("{}/{}", prefix, step))) {
+                continue;
+            }
+
+            let input = match input::read_input(calendar.year(), solution.day(), &InputSource::Default, FetchPolicy::Never) {
+                Ok(input) => input,
+                Err(_) => {
+                    println!("{:<24} skipped, no input", prefix);
+                    continue;
+                }
+            };
+            let parsed = match solution.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    println!("{:<24} skipped, input doesn't parse: {}", prefix, e);
+                    continue;
+                }
+            };
+
+            let name = format!("{}/parse", prefix);
+            if selected(&name) {
+                report(&name, measure(measurement, || drop(black_box(solution.parse(black_box(&input))))));
+            }
+            for part in 1..=2 {
+                let name = format!("{}/part{}", prefix, part);
+                if selected(&name) {
+                    report(&name, measure(measurement, || drop(black_box(solution.part(parsed.as_ref(), part)))));
+                }
+            }
+        }
+    }
+}
+
+/// Warms up, then samples `routine` for roughly `measurement` in total.
+fn measure(measurement: Duration, mut routine: impl FnMut()) -> Estimate {
+    let started = Instant::now();
+    let mut warm_up_iterations = 0u64;
+    while started.elapsed() < WARM_UP || warm_up_iterations == 0 {
+        routine();
+        warm_up_iterations += 1;
+    }
+    let per_iteration = started.elapsed() / warm_up_iterations as u32;
+
+    let samples = (measurement.as_secs_f64() / per_iteration.as_secs_f64().max(1e-9)) as usize;
+    let samples = samples.clamp(MIN_SAMPLES, SAMPLES);
+    let per_sample = measurement / samples as u32;
+    let iterations = (per_sample.as_secs_f64() / per_iteration.as_secs_f64().max(1e-9)).max(1.0) as u32;
+
+    let timings: Vec<Duration> = (0..samples)
+        .map(|_| {
+            let started = Instant::now();
+            for _ in 0..iterations {
+                routine();
+            }
+            started.elapsed() / iterations
+        })
+        .collect();
+    Estimate::from_samples(&timings)
+}
+
+fn report(name: &str, estimate: Estimate) {
+    let change = load_baseline(name).map_or(String::new(), |baseline| describe_change(&baseline, &estimate));
+    println!(
+        "{:<24} [{} {} {}]{}",
+        name,
+        format_duration(estimate.low()),
+        format_duration(estimate.mean),
+        format_duration(estimate.high()),
+        change
+    );
+    save_baseline(name, &estimate);
+}
+
+fn describe_change(baseline: &Estimate, estimate: &Estimate) -> String {
+    let change = (estimate.mean.as_secs_f64() / baseline.mean.as_secs_f64().max(1e-12) - 1.0) * 100.0;
+    let verdict = if estimate.high() < baseline.low() {
+        "improved"
+    } else if estimate.low() > baseline.high() {
+        "regressed"
+    } else {
+        "no change"
+    };
+    format!("  {:+.1}% ({})", change, verdict)
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target"), PathBuf::from);
+    target.join("aoc-bench").join(format!("{}.txt", name.replace('/', "-")))
+}
+
+/// Reads a saved `mean_ns margin_ns` line.
+fn load_baseline(name: &str) -> Option<Estimate> {
+    let text = fs::read_to_string(baseline_path(name)).ok()?;
+    let mut numbers = text.split_whitespace().map(|n| n.parse::<u64>().ok());
+    let mean = Duration::from_nanos(numbers.next()??);
+    let margin = Duration::from_nanos(numbers.next()??);
+    Some(Estimate { mean, margin })
+}
+
+fn save_baseline(name: &str, estimate: &Estimate) {
+    let path = baseline_path(name);
+    let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, format!("{} {}\n", estimate.mean.as_nanos(), estimate.margin.as_nanos())));
+    if let Err(e) = saved {
+        eprintln!("Could not save baseline {}: {}", path.display(), e);
+    }
+}
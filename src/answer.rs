@@ -0,0 +1,160 @@
+// src/answer.rs
+use std::fmt;
+use std::str::FromStr;
+
+/// The result of one part of a puzzle.
+///
+/// Numbers compare by value regardless of which variant holds them, so an
+/// `Int` computed by a solution matches a `BigInt` read back from disk.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    /// Multi-line output such as a rendered grid; compared line by line with
+    /// trailing whitespace ignored.
+    Grid(String),
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n as i128),
+            Answer::BigInt(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Grid(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        if let (Some(a), Some(b)) = (self.as_i128(), other.as_i128()) {
+            return a == b;
+        }
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Grid(a), Answer::Grid(b)) => {
+                a.lines().map(str::trim_end).eq(b.lines().map(str::trim_end))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        self.as_i128() == Some(*other as i128)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Answer>().is_ok_and(|answer| *self == answer)
+    }
+}
+
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err("empty answer".to_string());
+        }
+        if let Ok(n) = trimmed.parse::<i64>() {
+            Ok(Answer::Int(n))
+        } else if let Ok(n) = trimmed.parse::<i128>() {
+            Ok(Answer::BigInt(n))
+        } else if trimmed.contains('\n') {
+            Ok(Answer::Grid(trimmed.to_string()))
+        } else {
+            Ok(Answer::Text(trimmed.to_string()))
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::BigInt(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, usize);
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.contains('\n') {
+            Answer::Grid(s)
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::from(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_equality_across_widths() {
+        assert_eq!(Answer::Int(42), Answer::BigInt(42));
+        assert_eq!(Answer::from(42usize), 42);
+        assert_ne!(Answer::Int(42), Answer::Text("42".to_string()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("  143\n".parse::<Answer>(), Ok(Answer::Int(143)));
+        assert!(matches!("170141183460469231731687303715884105727".parse(), Ok(Answer::BigInt(_))));
+        assert!(matches!("RGZEHURK".parse(), Ok(Answer::Text(_))));
+        assert!(matches!("#..\n.#.".parse(), Ok(Answer::Grid(_))));
+        assert!("   ".parse::<Answer>().is_err());
+    }
+
+    #[test]
+    fn test_grid_ignores_trailing_whitespace() {
+        let computed = Answer::from("#..  \n.#.\n".to_string());
+        assert_eq!(computed, "#..\n.#.");
+        assert_eq!(computed.to_string(), "#..  \n.#.\n");
+    }
+}
@@ -0,0 +1,193 @@
+// src/answers.rs
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+
+/// Environment variable that overrides the directory holding `YYYY/dayXX.txt` answer files.
+pub const ANSWERS_DIR_ENV: &str = "AOC_ANSWERS_DIR";
+pub const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+/// Recorded answers for one day. Either part may be missing while the day is
+/// still in progress.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the answers in the answers-file format, which [`parse_answers`] reads back.
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            match answer {
+                Some(answer) if answer.is_multiline() => writeln!(f, "part{}:\n{}", part, answer)?,
+                Some(answer) => writeln!(f, "part{}: {}", part, answer)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "PASS"),
+            CheckStatus::Fail { expected } if expected.is_multiline() => {
+                write!(f, "FAIL, expected:\n{}", expected)
+            }
+            CheckStatus::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            CheckStatus::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+pub fn check(computed: &Answer, expected: Option<&Answer>) -> CheckStatus {
+    match expected {
+        Some(expected) if computed == expected => CheckStatus::Pass,
+        Some(expected) => CheckStatus::Fail { expected: expected.clone() },
+        None => CheckStatus::Missing,
+    }
+}
+
+pub fn answers_dir() -> PathBuf {
+    match env::var_os(ANSWERS_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_ANSWERS_DIR),
+    }
+}
+
+pub fn day_answers_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+/// Loads the recorded answers for a day; a missing file means nothing is recorded yet.
+pub fn load(year: u16, day: u8) -> Result<Expected, String> {
+    load_file(&day_answers_path(&answers_dir(), year, day))
+}
+
+pub fn load_file(path: &Path) -> Result<Expected, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_answers(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expected::default()),
+        Err(e) => Err(format!("Error reading answers file {}: {}", path.display(), e)),
+    }
+}
+
+/// Parses an answers file of the form
+///
+/// ```text
+/// part1: 143
+/// part2:
+/// #..#
+/// ####
+/// ```
+///
+/// An empty value after `partN:` takes the following lines, up to the next
+/// `partN:` header, as a multi-line answer. Lines starting with `//` are comments.
+pub fn parse_answers(text: &str) -> Result<Expected, String> {
+    let mut expected = Expected::default();
+    let mut current: Option<(u8, Vec<&str>)> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        if line.starts_with("//") {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':').filter(|(key, _)| key.starts_with("part")) {
+            store(&mut expected, current.take())?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                other => return Err(format!("line {}: unknown key '{}'", i + 1, other)),
+            };
+            current = Some((part, vec![value.trim()]));
+        } else {
+            match current.as_mut() {
+                Some((_, lines)) => lines.push(line),
+                None if line.trim().is_empty() => {}
+                None => return Err(format!("line {}: expected 'part1:' or 'part2:'", i + 1)),
+            }
+        }
+    }
+    store(&mut expected, current)?;
+
+    Ok(expected)
+}
+
+fn store(expected: &mut Expected, entry: Option<(u8, Vec<&str>)>) -> Result<(), String> {
+    let Some((part, lines)) = entry else {
+        return Ok(());
+    };
+    let slot = if part == 1 { &mut expected.part1 } else { &mut expected.part2 };
+    if slot.is_some() {
+        return Err(format!("part{} is recorded twice", part));
+    }
+
+    let text = lines.join("\n");
+    if !text.trim().is_empty() {
+        *slot = Some(text.parse()?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let expected = parse_answers("part1: 143\npart2: 123\n").unwrap();
+        assert_eq!(expected.part(1), Some(&Answer::Int(143)));
+        assert_eq!(expected.part(2), Some(&Answer::Int(123)));
+
+        let partial = parse_answers("// still working on part 2\npart1: 41\npart2:\n").unwrap();
+        assert_eq!(partial.part(1), Some(&Answer::Int(41)));
+        assert_eq!(partial.part(2), None);
+
+        let grid = parse_answers("part2:\n#..#\n####\n").unwrap();
+        assert_eq!(grid.part2, Some(Answer::Grid("#..#\n####".to_string())));
+
+        assert!(parse_answers("part3: 1").is_err());
+        assert!(parse_answers("part1: 1\npart1: 2").is_err());
+        assert!(parse_answers("143").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let expected = Expected { part1: Some(Answer::Grid("#..#\n####".to_string())), part2: Some(Answer::Int(6)) };
+        assert_eq!(expected.to_string(), "part1:\n#..#\n####\npart2: 6\n");
+        assert_eq!(parse_answers(&expected.to_string()), Ok(expected));
+        assert_eq!(Expected::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(&Answer::Int(41), Some(&Answer::Int(41))), CheckStatus::Pass);
+        assert_eq!(
+            check(&Answer::Int(40), Some(&Answer::Int(41))),
+            CheckStatus::Fail { expected: Answer::Int(41) }
+        );
+        assert_eq!(check(&Answer::Int(40), None), CheckStatus::Missing);
+    }
+}
@@ -0,0 +1,175 @@
+// src/bench.rs
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::solution::Runner;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings for one day; a part is `None` when it was excluded with `--part`.
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub name: &'static str,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    /// Median time of a full run: parse plus every benchmarked part.
+    pub fn total_median(&self) -> Duration {
+        self.parse.median
+            + self.part1.map_or(Duration::ZERO, |s| s.median)
+            + self.part2.map_or(Duration::ZERO, |s| s.median)
+    }
+}
+
+/// Runs parse and the selected parts `iterations` times each.
+pub fn bench_day(solution: &dyn Runner, input: &str, part: Option<u8>, iterations: usize) -> Result<DayBench> {
+    let iterations = iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(solution.parse(black_box(input))?);
+        parse.push(start.elapsed());
+
+        if part != Some(2) {
+            let start = Instant::now();
+            black_box(solution.part1(parsed.as_ref()));
+            part1.push(start.elapsed());
+        }
+        if part != Some(1) {
+            let start = Instant::now();
+            black_box(solution.part2(parsed.as_ref()));
+            part2.push(start.elapsed());
+        }
+    }
+
+    let stats = |samples: &[Duration]| (!samples.is_empty()).then(|| Stats::from_samples(samples));
+    Ok(DayBench {
+        day: solution.day(),
+        name: solution.name(),
+        parse: Stats::from_samples(&parse),
+        part1: stats(&part1),
+        part2: stats(&part2),
+    })
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn print_day(bench: &DayBench, iterations: usize) {
+    println!("Day {}: {} ({} iterations)", bench.day, bench.name, iterations);
+    println!("  {:<7} {:>10} {:>10} {:>10} {:>10}", "", "min", "median", "mean", "stddev");
+    let phases = [("parse", Some(bench.parse)), ("part 1", bench.part1), ("part 2", bench.part2)];
+    for (label, stats) in phases {
+        if let Some(s) = stats {
+            println!(
+                "  {:<7} {:>10} {:>10} {:>10} {:>10}",
+                label,
+                format_duration(s.min),
+                format_duration(s.median),
+                format_duration(s.mean),
+                format_duration(s.stddev)
+            );
+        }
+    }
+}
+
+/// Prints the median of each phase per day, with a grand total row.
+pub fn print_summary(benches: &[DayBench]) {
+    let median = |stats: Option<Stats>| stats.map_or("-".to_string(), |s| format_duration(s.median));
+
+    println!("{:<5} {:>10} {:>10} {:>10} {:>10}", "Day", "parse", "part 1", "part 2", "total");
+    let mut total = Duration::ZERO;
+    for bench in benches {
+        total += bench.total_median();
+        println!(
+            "{:<5} {:>10} {:>10} {:>10} {:>10}",
+            bench.day,
+            format_duration(bench.parse.median),
+            median(bench.part1),
+            median(bench.part2),
+            format_duration(bench.total_median())
+        );
+    }
+    println!("{:<5} {:>10} {:>10} {:>10} {:>10}", "Total", "", "", "", format_duration(total));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        // Population standard deviation of 1, 2, 3, 4 is sqrt(1.25)
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.34µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+}
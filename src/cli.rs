@@ -0,0 +1,638 @@
+// src/cli.rs
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code::bench::DEFAULT_ITERATIONS;
+use advent_of_code::input::{FetchPolicy, InputSource, INPUT_DIR_ENV};
+use advent_of_code::report::Format;
+use advent_of_code::runner::RunConfig;
+
+pub const USAGE: &str = "\
+Usage: AdventOfCode [run] <DAYS>... [OPTIONS]
+       AdventOfCode new <DAY> [--year <YEAR>] [--name <TITLE>]
+       AdventOfCode history [<DAYS>...] [--year <YEAR>] [--part <1|2>] [--limit <N>]
+       AdventOfCode render <DAY> [--year <YEAR>] [--input <FILE>] [--animate]
+                    [--delay <MS>] [--every <N>] [--svg <FILE>] [--ppm <FILE>]
+                    [--scale <N>]
+
+DAYS can be a single day (5), a range (1-6), a comma separated list (1,3,5)
+or `all` for every implemented day. Ranges and `all` skip days that have no
+solution yet.
+
+Options:
+  -y, --year <YEAR>   Event year to run (default: the latest with solutions)
+  -p, --part <1|2>    Only run the given part
+  -i, --input <FILE>  Read the puzzle input from FILE (`-` for stdin) instead
+                      of YEAR/dayXX.txt in $AOC_INPUT_DIR (default: src/input)
+      --fetch         Download missing inputs without asking ($AOC_SESSION)
+      --no-fetch      Never download missing inputs
+  -c, --check         Compare answers against answers/YEAR/dayXX.txt
+                      ($AOC_ANSWERS_DIR) and fail on any mismatch
+  -f, --format <FMT>  Output format: text (default), json or csv
+  -t, --timeout <SECS>
+                      Give up on a day after SECS seconds (fractions allowed)
+  -j, --jobs <N>      Run up to N days in parallel and finish with a summary
+                      table
+  -w, --watch         Re-run the day whenever its input or examples change,
+                      rebuilding first when a source file changes
+      --no-history    Don't record this run in .aoc/history.jsonl ($AOC_HISTORY)
+  -b, --bench         Time parse, part 1 and part 2 instead of printing answers
+  -n, --iterations <N>
+                      Number of benchmark iterations per day (default: 10)
+  -v, --verbose       Log progress to stderr (repeat as -vv/-vvv for more)
+  -q, --quiet         Only log errors
+  -h, --help          Show this message
+
+`new` creates src/solutions/yYEAR/dXX.rs from a template, registers it and
+adds empty input and example files. It never overwrites an existing day.
+
+Every run on the real input appends its answers and timings to the history.
+`history` shows the last N runs of each part (default: 10), flagging answers
+that changed and parts that got over 20% slower than the last run made the
+same way (debug or release build, threaded or not); it exits with 1 if the
+latest run of any part shown was flagged.
+
+`render` draws how a day was solved (so far 2024 day 6: the guard's route,
+with O where an obstacle would cause a loop). It prints the picture, or with
+--animate plays it in the terminal, redrawing every N steps (--every, default
+1) after a pause (--delay, default 50ms). --svg and --ppm write images
+instead, --scale setting the PPM's pixels per cell (default 4).";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySpec {
+    Single(u8),
+    Range(u8, u8),
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    /// `None` means the latest registered year.
+    pub year: Option<u16>,
+    pub days: Vec<DaySpec>,
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub fetch: FetchPolicy,
+    pub check: bool,
+    pub format: Format,
+    /// Worker threads for running days; `Some` also asks for a summary table.
+    pub jobs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub watch: bool,
+    pub bench: bool,
+    pub iterations: usize,
+    /// Append the answers to the run history.
+    pub history: bool,
+    /// Net count of `-v` minus `-q` flags.
+    pub verbosity: i8,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            year: None,
+            days: Vec::new(),
+            part: None,
+            input: InputSource::Default,
+            fetch: FetchPolicy::Ask,
+            check: false,
+            format: Format::Text,
+            jobs: None,
+            timeout: None,
+            watch: false,
+            bench: false,
+            iterations: DEFAULT_ITERATIONS,
+            history: true,
+            verbosity: 0,
+        }
+    }
+}
+
+impl RunOptions {
+    pub fn run_config(&self) -> RunConfig {
+        RunConfig {
+            input: self.input.clone(),
+            fetch: self.fetch,
+            part: self.part,
+            check: self.check,
+            timeout: self.timeout,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(RunOptions),
+    New { year: Option<u16>, day: u8, name: Option<String> },
+    History(HistoryOptions),
+    Render(RenderOptions),
+    Help,
+}
+
+/// Which parts `history` shows; no days means every day in the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryOptions {
+    pub year: Option<u16>,
+    pub days: Vec<DaySpec>,
+    pub part: Option<u8>,
+    /// Runs shown per part.
+    pub limit: usize,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        HistoryOptions { year: None, days: Vec::new(), part: None, limit: DEFAULT_HISTORY_LIMIT }
+    }
+}
+
+const DEFAULT_HISTORY_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub input: InputSource,
+    pub animate: bool,
+    /// Pause after each animation frame.
+    pub delay: Duration,
+    /// Steps per animation frame.
+    pub every: usize,
+    pub svg: Option<PathBuf>,
+    pub ppm: Option<PathBuf>,
+    /// Pixels per cell in a PPM.
+    pub scale: usize,
+}
+
+impl RenderOptions {
+    fn new(day: u8) -> Self {
+        RenderOptions {
+            year: None,
+            day,
+            input: InputSource::Default,
+            animate: false,
+            delay: Duration::from_millis(50),
+            every: 1,
+            svg: None,
+            ppm: None,
+            scale: 4,
+        }
+    }
+}
+
+/// Parses the arguments that follow the program name.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("run") => {
+            args.next();
+        }
+        Some("new") => {
+            args.next();
+            return parse_new(args);
+        }
+        Some("history") => {
+            args.next();
+            return parse_history(args);
+        }
+        Some("render") => {
+            args.next();
+            return parse_render(args);
+        }
+        _ => {}
+    }
+
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.year = Some(parse_year(&value)?);
+            }
+            "-p" | "--part" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.part = Some(parse_part(&value)?);
+            }
+            "-i" | "--input" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.input = InputSource::from_arg(&value);
+            }
+            "--fetch" => options.fetch = FetchPolicy::Always,
+            "--no-fetch" => options.fetch = FetchPolicy::Never,
+            "-c" | "--check" => options.check = true,
+            "-f" | "--format" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.format = value.parse()?;
+            }
+            "-j" | "--jobs" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.jobs = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid job count '{}'", value)),
+                };
+            }
+            "-t" | "--timeout" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.timeout = match value.parse::<f64>() {
+                    Ok(secs) if secs > 0.0 && secs.is_finite() => Some(Duration::from_secs_f64(secs)),
+                    _ => return Err(format!("Invalid timeout '{}', expected a number of seconds", value)),
+                };
+            }
+            "-w" | "--watch" => options.watch = true,
+            "--no-history" => options.history = false,
+            "-b" | "--bench" => options.bench = true,
+            "-n" | "--iterations" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.iterations = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid iteration count '{}'", value)),
+                };
+            }
+            "--verbose" => options.verbosity += 1,
+            "-q" | "--quiet" => options.verbosity -= 1,
+            flag if flag.len() > 1 && flag.starts_with('-') && flag[1..].chars().all(|c| c == 'v') => {
+                options.verbosity += (flag.len() - 1) as i8;
+            }
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {}", flag)),
+            days => {
+                for spec in days.split(',') {
+                    options.days.push(parse_day_spec(spec)?);
+                }
+            }
+        }
+    }
+
+    if options.days.is_empty() {
+        return Err("Please provide the day number as an argument (1-25)".to_string());
+    }
+    if options.input != InputSource::Default && !matches!(options.days.as_slice(), [DaySpec::Single(_)]) {
+        return Err(format!(
+            "--input can only be used with a single day; set {} to point several days at another directory",
+            INPUT_DIR_ENV
+        ));
+    }
+
+    if options.bench && options.format != Format::Text {
+        return Err("--format only applies to answers, not --bench".to_string());
+    }
+    if options.watch {
+        if !matches!(options.days.as_slice(), [DaySpec::Single(_)]) {
+            return Err("--watch can only be used with a single day".to_string());
+        }
+        if options.bench || options.format != Format::Text || options.input == InputSource::Stdin {
+            return Err("--watch can't be combined with --bench, --format or --input -".to_string());
+        }
+    }
+
+    Ok(Command::Run(options))
+}
+
+fn parse_new<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut name = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                year = Some(parse_year(&value)?);
+            }
+            "--name" => name = Some(args.next().ok_or("--name needs a value")?),
+            flag if flag.starts_with('-') => return Err(format!("Unknown option for new: {}", flag)),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("new takes a single day, got '{}' as well", value)),
+        }
+    }
+    let day = day.ok_or("Please provide the day to create (1-25)")?;
+    Ok(Command::New { year, day, name })
+}
+
+fn parse_history<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = HistoryOptions::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.year = Some(parse_year(&value)?);
+            }
+            "-p" | "--part" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.part = Some(parse_part(&value)?);
+            }
+            "-l" | "--limit" => {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+                options.limit = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid limit '{}'", value)),
+                };
+            }
+            flag if flag.starts_with('-') => return Err(format!("Unknown option for history: {}", flag)),
+            days => {
+                for spec in days.split(',') {
+                    options.days.push(parse_day_spec(spec)?);
+                }
+            }
+        }
+    }
+    Ok(Command::History(options))
+}
+
+fn parse_render<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = RenderOptions::new(0);
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => options.year = Some(parse_year(&value()?)?),
+            "-i" | "--input" => options.input = InputSource::from_arg(&value()?),
+            "-a" | "--animate" => options.animate = true,
+            "--delay" => {
+                let value = value()?;
+                options.delay = match value.parse() {
+                    Ok(millis) => Duration::from_millis(millis),
+                    _ => return Err(format!("Invalid delay '{}', expected milliseconds", value)),
+                };
+            }
+            "--every" | "--scale" => {
+                let value = value()?;
+                let n = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid {} '{}'", &arg[2..], value)),
+                };
+                if arg == "--every" {
+                    options.every = n;
+                } else {
+                    options.scale = n;
+                }
+            }
+            "--svg" => options.svg = Some(PathBuf::from(value()?)),
+            "--ppm" => options.ppm = Some(PathBuf::from(value()?)),
+            flag if flag.starts_with('-') => return Err(format!("Unknown option for render: {}", flag)),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("render takes a single day, got '{}' as well", value)),
+        }
+    }
+    options.day = day.ok_or("Please provide the day to render (1-25)")?;
+    Ok(Command::Render(options))
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.parse::<u16>() {
+        Ok(n) if (2015..=9999).contains(&n) => Ok(n),
+        _ => Err(format!("Invalid year '{}', Advent of Code started in 2015", value)),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part '{}', expected 1 or 2", value)),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(n) if (1..=25).contains(&n) => Ok(n),
+        _ => Err(format!("Please provide a valid day number between 1 and 25, got '{}'", value)),
+    }
+}
+
+fn parse_day_spec(spec: &str) -> Result<DaySpec, String> {
+    if spec == "all" {
+        return Ok(DaySpec::All);
+    }
+    match spec.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("Invalid day range {}", spec));
+            }
+            Ok(DaySpec::Range(from, to))
+        }
+        None => Ok(DaySpec::Single(parse_day(spec)?)),
+    }
+}
+
+/// Expands day specs against the implemented days, keeping calendar order.
+///
+/// A single day that isn't implemented is an error; ranges and `all` quietly
+/// skip missing days so `1-25` works on a partially solved calendar.
+pub fn resolve_days(specs: &[DaySpec], available: &[u8]) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for spec in specs {
+        match *spec {
+            DaySpec::Single(day) if !available.contains(&day) => {
+                let available: Vec<String> = available.iter().map(u8::to_string).collect();
+                return Err(format!("Day {} not implemented yet (available: {})", day, available.join(", ")));
+            }
+            DaySpec::Single(day) => days.push(day),
+            DaySpec::Range(from, to) => days.extend(available.iter().filter(|&&d| d >= from && d <= to)),
+            DaySpec::All => days.extend(available),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse("run 5 --part 2"),
+            Ok(Command::Run(RunOptions {
+                days: vec![DaySpec::Single(5)],
+                part: Some(2),
+                ..RunOptions::default()
+            }))
+        );
+        assert_eq!(
+            parse("1-6,all"),
+            Ok(Command::Run(RunOptions {
+                days: vec![DaySpec::Range(1, 6), DaySpec::All],
+                ..RunOptions::default()
+            }))
+        );
+        assert_eq!(parse("--help"), Ok(Command::Help));
+        assert!(parse("").is_err());
+        assert!(parse("26").is_err());
+        assert!(parse("6-1").is_err());
+        assert!(parse("5 --part 3").is_err());
+        assert!(parse("5 --bogus").is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(
+            parse("1-3 --year 2015"),
+            Ok(Command::Run(RunOptions {
+                year: Some(2015),
+                days: vec![DaySpec::Range(1, 3)],
+                ..RunOptions::default()
+            }))
+        );
+        assert!(parse("1 -y 2014").is_err());
+        assert!(parse("1 -y 24").is_err());
+        assert!(parse("1 -y").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        match parse("6 --input -") {
+            Ok(Command::Run(options)) => assert_eq!(options.input, InputSource::Stdin),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("1-6 --input alt.txt").is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse("new 7"), Ok(Command::New { year: None, day: 7, name: None }));
+        assert_eq!(
+            parse("new 07 --name Bridge --year 2023"),
+            Ok(Command::New { year: Some(2023), day: 7, name: Some("Bridge".to_string()) })
+        );
+        assert!(parse("new").is_err());
+        assert!(parse("new 26").is_err());
+        assert!(parse("new 7 8").is_err());
+        assert!(parse("new 7 --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_history() {
+        assert_eq!(parse("history"), Ok(Command::History(HistoryOptions::default())));
+        assert_eq!(
+            parse("history 6 --part 2 --limit 3"),
+            Ok(Command::History(HistoryOptions {
+                days: vec![DaySpec::Single(6)],
+                part: Some(2),
+                limit: 3,
+                ..HistoryOptions::default()
+            }))
+        );
+        assert!(parse("history -l 0").is_err());
+        assert!(parse("history 6 --check").is_err());
+
+        match parse("6 --no-history") {
+            Ok(Command::Run(options)) => assert!(!options.history),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_render() {
+        assert_eq!(parse("render 6"), Ok(Command::Render(RenderOptions::new(6))));
+        assert_eq!(
+            parse("render 6 --animate --every 10 --delay 0 --svg route.svg"),
+            Ok(Command::Render(RenderOptions {
+                animate: true,
+                every: 10,
+                delay: Duration::ZERO,
+                svg: Some(PathBuf::from("route.svg")),
+                ..RenderOptions::new(6)
+            }))
+        );
+        assert!(parse("render").is_err());
+        assert!(parse("render 6 7").is_err());
+        assert!(parse("render 6 --scale 0").is_err());
+        assert!(parse("render 6 --ppm").is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        match parse("run all --jobs 4") {
+            Ok(Command::Run(options)) => assert_eq!(options.jobs, Some(4)),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("all -j 0").is_err());
+        assert!(parse("all -j").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        match parse("all --timeout 2.5") {
+            Ok(Command::Run(options)) => assert_eq!(options.timeout, Some(Duration::from_millis(2500))),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("all -t 0").is_err());
+        assert!(parse("all -t soon").is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        match parse("6 --watch") {
+            Ok(Command::Run(options)) => assert!(options.watch),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("1-6 -w").is_err());
+        assert!(parse("6 -w --bench").is_err());
+        assert!(parse("6 -w --input -").is_err());
+        assert!(parse("6 -w --input alt.txt").is_ok());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let fetch = |args: &str| match parse(args) {
+            Ok(Command::Run(options)) => options.fetch,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(fetch("7"), FetchPolicy::Ask);
+        assert_eq!(fetch("7 --fetch"), FetchPolicy::Always);
+        assert_eq!(fetch("all --no-fetch"), FetchPolicy::Never);
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        match parse("all --bench -n 50") {
+            Ok(Command::Run(options)) => {
+                assert!(options.bench);
+                assert_eq!(options.iterations, 50);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("all --bench -n 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        let verbosity = |args: &str| match parse(args) {
+            Ok(Command::Run(options)) => options.verbosity,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(verbosity("6"), 0);
+        assert_eq!(verbosity("6 -vv"), 2);
+        assert_eq!(verbosity("6 -v --verbose"), 2);
+        assert_eq!(verbosity("6 -q"), -1);
+        assert!(parse("6 -vx").is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        match parse("all --format csv --check") {
+            Ok(Command::Run(options)) => assert_eq!(options.format, Format::Csv),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("all --format yaml").is_err());
+        assert!(parse("all --format json --bench").is_err());
+    }
+
+    #[test]
+    fn test_resolve_days() {
+        let available = [1, 2, 3, 5];
+        assert_eq!(resolve_days(&[DaySpec::Range(2, 25)], &available), Ok(vec![2, 3, 5]));
+        assert_eq!(resolve_days(&[DaySpec::Single(5), DaySpec::All], &available), Ok(vec![1, 2, 3, 5]));
+        assert!(resolve_days(&[DaySpec::Single(4)], &available).is_err());
+    }
+}
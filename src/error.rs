@@ -0,0 +1,90 @@
+// src/error.rs
+use std::fmt;
+use std::str::FromStr;
+
+/// Crate-wide error for puzzle inputs that can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// Malformed input at a 1-based line and column.
+    Parse { line: usize, column: usize, message: String },
+    /// The input as a whole is unusable, e.g. empty or missing a section.
+    Input(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    /// Builds a parse error pointing at `token`, which must be a slice of
+    /// `line`; `line_index` is 0-based as produced by `lines().enumerate()`.
+    pub fn at(line_index: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: line_index + 1,
+            column: column_of(line, token),
+            message: message.into(),
+        }
+    }
+
+    /// Builds a parse error for a whole line.
+    pub fn line(line_index: usize, message: impl Into<String>) -> Self {
+        AocError::Parse { line: line_index + 1, column: 1, message: message.into() }
+    }
+
+    pub fn input(message: impl Into<String>) -> Self {
+        AocError::Input(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse { line, column, message } => {
+                write!(f, "line {}, column {}: {}", line, column, message)
+            }
+            AocError::Input(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// 1-based column of `token` within `line`, falling back to 1 if `token`
+/// isn't actually a slice of `line`.
+fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// Parses `token` (a slice of `line`) as a number, reporting where it failed.
+pub fn parse_number<T: FromStr>(line_index: usize, line: &str, token: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| AocError::at(line_index, line, token, format!("expected a number, found '{}'", token)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_reports_column() {
+        let line = "12   x4";
+        let token = line.split_whitespace().nth(1).unwrap();
+        let err = parse_number::<i64>(2, line, token).unwrap_err();
+        assert_eq!(
+            err,
+            AocError::Parse { line: 3, column: 6, message: "expected a number, found 'x4'".to_string() }
+        );
+        assert_eq!(err.to_string(), "line 3, column 6: expected a number, found 'x4'");
+        assert_eq!(parse_number::<u32>(0, line, &line[..2]), Ok(12));
+    }
+
+    #[test]
+    fn test_column_of_foreign_token() {
+        let foreign = String::from("b");
+        assert_eq!(column_of("abc", &foreign), 1);
+    }
+}
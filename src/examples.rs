@@ -0,0 +1,86 @@
+// src/examples.rs
+//! Puzzle examples stored as data files: `examples/YYYY/dayXX/<name>.txt` holds
+//! an input and `examples/YYYY/dayXX/<name>.answers` the expected answers, in the same
+//! format as the `answers/` store.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, Expected};
+use crate::log::warning;
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub input_path: PathBuf,
+    pub expected: Expected,
+}
+
+pub fn day_examples_dir(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{:02}", day))
+}
+
+/// Finds every example for a day, sorted by name. A day without an examples
+/// directory simply has no examples, and examples whose answers file is still
+/// blank are skipped with a warning.
+pub fn discover(dir: &Path, year: u16, day: u8) -> Result<Vec<Example>, String> {
+    let day_dir = day_examples_dir(dir, year, day);
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let input_path = entry.map_err(|e| e.to_string())?.path();
+        if input_path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let answers_path = input_path.with_extension("answers");
+        let expected = answers::load_file(&answers_path)?;
+        if expected == Expected::default() {
+            // Most likely a day fresh from `new` whose answers aren't filled in yet
+            warning!("Skipping {}: no expected answers in {}", input_path.display(), answers_path.display());
+            continue;
+        }
+
+        let name = input_path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        examples.push(Example { name, input_path, expected });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn examples_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR)
+    }
+
+    #[test]
+    fn test_discover_skips_missing_day() {
+        assert!(discover(&examples_dir(), 2024, 25).unwrap().is_empty());
+        assert!(discover(&examples_dir(), 2015, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_discover_skips_blank_answers() {
+        let dir = TempDir::new("examples");
+        let day_dir = day_examples_dir(&dir, 2024, 7);
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("example.txt"), "").unwrap();
+        fs::write(day_dir.join("example.answers"), "part1:\npart2:\n").unwrap();
+        fs::write(day_dir.join("small.txt"), "1\n").unwrap();
+        fs::write(day_dir.join("small.answers"), "part1: 1\npart2:\n").unwrap();
+
+        let examples = discover(&dir, 2024, 7).unwrap();
+        assert_eq!(examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), ["small"]);
+    }
+}
@@ -0,0 +1,266 @@
+// src/geometry.rs
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a grid, with rows growing downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row.abs_diff(other.row).max(self.col.abs_diff(other.col))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(p: Point) -> Self {
+        (p.row, p.col)
+    }
+}
+
+/// The four orthogonal directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [Direction4::Up, Direction4::Right, Direction4::Down, Direction4::Left];
+
+    pub fn turn_right(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction4::Up => Point::new(-1, 0),
+            Direction4::Right => Point::new(0, 1),
+            Direction4::Down => Point::new(1, 0),
+            Direction4::Left => Point::new(0, -1),
+        }
+    }
+
+    /// Parses the arrow characters `^`, `>`, `v` and `<`.
+    pub fn from_arrow(ch: char) -> Option<Direction4> {
+        match ch {
+            '^' => Some(Direction4::Up),
+            '>' => Some(Direction4::Right),
+            'v' => Some(Direction4::Down),
+            '<' => Some(Direction4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::Up => '^',
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+        }
+    }
+}
+
+/// The eight compass directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees anticlockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction8::Up => Point::new(-1, 0),
+            Direction8::UpRight => Point::new(-1, 1),
+            Direction8::Right => Point::new(0, 1),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(1, 0),
+            Direction8::DownLeft => Point::new(1, -1),
+            Direction8::Left => Point::new(0, -1),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = Direction8;
+
+    /// Fails for the diagonal directions, returning them unchanged.
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        if dir.is_diagonal() {
+            Err(dir)
+        } else {
+            Ok(Direction4::ALL[dir as usize / 2])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-1, 4);
+        assert_eq!(a + b, Point::new(1, 1));
+        assert_eq!(a - b, Point::new(3, -7));
+        assert_eq!(a * 3, Point::new(6, -9));
+        assert_eq!(-a, Point::new(-2, 3));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 7);
+    }
+
+    #[test]
+    fn test_direction4_turns() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        for dir in Direction4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            assert_eq!(Direction4::from_arrow(dir.arrow()), Some(dir));
+        }
+    }
+
+    #[test]
+    fn test_direction8_turns_and_conversions() {
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+        for dir in Direction4::ALL {
+            let eight = Direction8::from(dir);
+            assert_eq!(eight.delta(), dir.delta());
+            assert_eq!(Direction4::try_from(eight), Ok(dir));
+        }
+        assert_eq!(Direction4::try_from(Direction8::DownLeft), Err(Direction8::DownLeft));
+    }
+}
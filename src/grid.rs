@@ -0,0 +1,310 @@
+// src/grid.rs
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{AocError, Result};
+use crate::geometry::{Direction4, Direction8, Point};
+
+/// A rectangular grid stored row-major in a single `Vec`.
+///
+/// Cells are addressed by `Point`; lookups that may step off the edge return
+/// `None` instead of panicking.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cell count doesn't match {}x{}", width, height);
+        Grid { width, height, cells }
+    }
+
+    /// Parses one cell per character. Trailing blank lines are ignored, but a
+    /// blank line anywhere else is an error. `f` rejects a character by
+    /// returning a message, which is reported with its line and column.
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(char) -> std::result::Result<T, String>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let mut blank = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                blank = blank.or(Some(i));
+                continue;
+            }
+            if let Some(blank) = blank {
+                return Err(AocError::line(blank, "blank line inside the grid"));
+            }
+
+            let start = cells.len();
+            for (offset, ch) in line.char_indices() {
+                cells.push(f(ch).map_err(|message| AocError::at(i, line, &line[offset..], message))?);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::line(i, format!("expected {} cells, found {}", width, row_width)));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid { width, height, cells }),
+            None => Err(AocError::input("empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.row >= 0 && p.col >= 0 && (p.row as usize) < self.height && (p.col as usize) < self.width
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.in_bounds(p).then(|| p.row as usize * self.width + p.col as usize)
+    }
+
+    fn point_at(&self, index: usize) -> Point {
+        Point::new((index / self.width) as isize, (index % self.width) as isize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.point_at(i), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Cells from `start` moving by `step` until the edge.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = &T> {
+        (0..)
+            .map(move |i| self.get(start + step * i))
+            .take_while(Option::is_some)
+            .flatten()
+    }
+
+    /// Diagonals running down-right, starting from the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height).rev().map(|row| (row, 0)).chain((1..self.width).map(|col| (0, col)));
+        starts.map(move |start| self.ray(start.into(), Direction8::DownRight.delta()))
+    }
+
+    /// Diagonals running down-left, starting from the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width - 1;
+        let starts = (0..self.width).map(|col| (0, col)).chain((1..self.height).map(move |row| (row, last)));
+        starts.map(move |start| self.ray(start.into(), Direction8::DownLeft.delta()))
+    }
+
+    /// In-bounds cells at each of the given offsets from `p`.
+    pub fn neighbours(&self, p: Point, offsets: impl IntoIterator<Item = Point>) -> impl Iterator<Item = (Point, &T)> {
+        offsets.into_iter().filter_map(move |offset| self.get(p + offset).map(|cell| (p + offset, cell)))
+    }
+
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(p, Direction4::ALL.map(Direction4::delta))
+    }
+
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(p, Direction8::ALL.map(Direction8::delta))
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|i| self.point_at(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |row, col| (col, row))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(self.height, self.width, |row, col| (self.height - 1 - col, row))
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(self.height, self.width, |row, col| (col, self.width - 1 - row))
+    }
+
+    /// Builds a `width` x `height` grid whose cell `(row, col)` is taken
+    /// from `source(row, col)` in this grid.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> (usize, usize)) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let (r, c) = source(row, col);
+                self.cells[r * self.width + c].clone()
+            })
+            .collect();
+        Grid { width, height, cells }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Self> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{} out of bounds", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let i = self.index_of(p).unwrap_or_else(|| panic!("{} out of bounds", p));
+        &mut self.cells[i]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn test_parse_and_access() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&'d'));
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(
+            Grid::parse("ab\nabc").unwrap_err().to_string(),
+            "line 2, column 1: expected 2 cells, found 3"
+        );
+        assert!(Grid::parse("\n\n").is_err());
+        assert_eq!(Grid::parse("abc\ndef\n\n\n").unwrap(), sample());
+        assert_eq!(
+            Grid::parse("abc\n\ndef\n").unwrap_err().to_string(),
+            "line 2, column 1: blank line inside the grid"
+        );
+        assert!(Grid::parse("\nabc\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_rejects_cells() {
+        let err = Grid::parse_with(".#\n#x", |ch| match ch {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(format!("unexpected '{}'", ch)),
+        });
+        assert_eq!(err.unwrap_err().to_string(), "line 2, column 2: unexpected 'x'");
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = sample();
+        assert_eq!(collect(grid.column(1)), "be");
+        let diagonals: Vec<String> = grid.diagonals().map(collect).collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti: Vec<String> = grid.anti_diagonals().map(collect).collect();
+        assert_eq!(anti, ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let n4: String = grid.neighbours4(Point::ORIGIN).map(|(_, &c)| c).collect();
+        assert_eq!(n4, "bd");
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,609 @@
+// src/history.rs
+//! An append-only log of every run's answers and timings, one JSON object per
+//! line in `.aoc/history.jsonl`:
+//!
+//! ```text
+//! {"timestamp": 1733472000, "commit": "3f2a9c1", "year": 2024, "day": 6, "part": 2, "answer": "1719", "kind": "int", "time_ns": 1204000000, "profile": "release", "threaded": false}
+//! ```
+//!
+//! Each new entry is compared with the previous run of the same part, which
+//! flags answers that changed, and with the previous run made under the same
+//! [`Conditions`], which flags parts that got noticeably slower.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{self, Write as _};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answer::Answer;
+use crate::bench::format_duration;
+use crate::log::warning;
+use crate::report::json_string;
+use crate::runner::DayResult;
+
+/// Environment variable that overrides where the history is kept.
+pub const HISTORY_PATH_ENV: &str = "AOC_HISTORY";
+pub const DEFAULT_HISTORY_PATH: &str = ".aoc/history.jsonl";
+
+/// How much slower than its previous run a part may get before it's flagged.
+pub const SLOWDOWN_THRESHOLD: f64 = 0.20;
+/// Parts faster than this are never flagged as slower; at this scale the
+/// difference between two runs is mostly noise.
+pub const MIN_FLAGGED_TIME: Duration = Duration::from_micros(100);
+
+/// How a run was made. Timings are only comparable between runs made the
+/// same way: a debug build is many times slower than a release one, and days
+/// running side by side on threads compete for the CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conditions {
+    pub release: bool,
+    /// The days ran on their own threads, for `--jobs` or `--timeout`.
+    pub threaded: bool,
+}
+
+impl Conditions {
+    /// The conditions of a run made by this build.
+    pub fn current(threaded: bool) -> Self {
+        Conditions { release: !cfg!(debug_assertions), threaded }
+    }
+}
+
+impl fmt::Display for Conditions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(if self.release { "release" } else { "debug" })?;
+        if self.threaded {
+            f.write_str(" threaded")?;
+        }
+        Ok(())
+    }
+}
+
+/// One part of one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short hash of `HEAD`, suffixed with `-dirty` when the tree had
+    /// uncommitted changes; `None` outside a git checkout.
+    pub commit: Option<String>,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+    /// `None` for entries recorded before conditions were.
+    pub conditions: Option<Conditions>,
+}
+
+impl Entry {
+    fn key(&self) -> (u16, u8, u8) {
+        (self.year, self.day, self.part)
+    }
+
+    /// Renders the entry as a single JSON line (without the newline).
+    pub fn to_json(&self) -> String {
+        let conditions = match self.conditions {
+            Some(conditions) => format!(
+                ", \"profile\": {}, \"threaded\": {}",
+                json_string(if conditions.release { "release" } else { "debug" }),
+                conditions.threaded
+            ),
+            None => String::new(),
+        };
+        format!(
+            "{{\"timestamp\": {}, \"commit\": {}, \"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"kind\": {}, \"time_ns\": {}{}}}",
+            self.timestamp,
+            self.commit.as_deref().map_or("null".to_string(), json_string),
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.answer.to_string()),
+            json_string(answer_kind(&self.answer)),
+            self.time.as_nanos(),
+            conditions
+        )
+    }
+
+    pub fn from_json(line: &str) -> Result<Entry, String> {
+        let mut fields = parse_object(line)?;
+        let kind = fields.remove("kind");
+        let mut take = |name: &str| fields.remove(name).ok_or_else(|| format!("missing \"{}\"", name));
+        let commit = match take("commit")? {
+            Value::String(commit) => Some(commit),
+            Value::Null => None,
+            _ => return Err("\"commit\" should be a string".to_string()),
+        };
+        let answer = match (take("answer")?, kind) {
+            (Value::String(answer), Some(Value::String(kind))) => answer_of_kind(answer, &kind)?,
+            // Entries from before the kind was recorded
+            (Value::String(answer), None) => answer.parse()?,
+            _ => return Err("\"answer\" and \"kind\" should be strings".to_string()),
+        };
+        Ok(Entry {
+            timestamp: number(take("timestamp")?, "timestamp")?,
+            commit,
+            year: number(take("year")?, "year")?,
+            day: number(take("day")?, "day")?,
+            part: number(take("part")?, "part")?,
+            answer,
+            time: Duration::from_nanos(number(take("time_ns")?, "time_ns")?),
+            conditions: match (fields.remove("profile"), fields.remove("threaded")) {
+                (None, None) => None,
+                (Some(Value::String(profile)), Some(Value::Bool(threaded))) => Some(Conditions {
+                    release: match profile.as_str() {
+                        "release" => true,
+                        "debug" => false,
+                        _ => return Err(format!("unknown profile \"{}\"", profile)),
+                    },
+                    threaded,
+                }),
+                _ => return Err("\"profile\" should be a string and \"threaded\" a boolean".to_string()),
+            },
+        })
+    }
+}
+
+/// Which [`Answer`] variant the history holds, so it's read back exactly as
+/// it was, whitespace and all, rather than reparsed.
+fn answer_kind(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Int(_) => "int",
+        Answer::BigInt(_) => "bigint",
+        Answer::Text(_) => "text",
+        Answer::Grid(_) => "grid",
+    }
+}
+
+fn answer_of_kind(value: String, kind: &str) -> Result<Answer, String> {
+    let bad_number = |_| format!("bad {} answer \"{}\"", kind, value);
+    match kind {
+        "int" => value.parse().map(Answer::Int).map_err(bad_number),
+        "bigint" => value.parse().map(Answer::BigInt).map_err(bad_number),
+        "text" => Ok(Answer::Text(value)),
+        "grid" => Ok(Answer::Grid(value)),
+        _ => Err(format!("unknown answer kind \"{}\"", kind)),
+    }
+}
+
+/// Something worth pointing out about a run compared with the one before it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Flag {
+    AnswerChanged { was: Answer },
+    /// `ratio` is the new time over the old one.
+    Slower { was: Duration, ratio: f64 },
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Flag::AnswerChanged { was } if was.is_multiline() => write!(f, "answer changed"),
+            Flag::AnswerChanged { was } => write!(f, "answer changed (was {})", was),
+            Flag::Slower { was, ratio } => {
+                write!(f, "{:.0}% slower (was {})", (ratio - 1.0) * 100.0, format_duration(*was))
+            }
+        }
+    }
+}
+
+/// Flags `current` against `earlier` runs of the same part, oldest first: its
+/// answer against the latest of them, its time against the latest made under
+/// the same conditions.
+pub fn compare(earlier: &[&Entry], current: &Entry) -> Vec<Flag> {
+    let mut flags = Vec::new();
+    if let Some(previous) = earlier.last().filter(|previous| previous.answer != current.answer) {
+        flags.push(Flag::AnswerChanged { was: previous.answer.clone() });
+    }
+    if let Some((previous, ratio)) = comparable_time(earlier, current) {
+        if current.time >= MIN_FLAGGED_TIME && ratio > 1.0 + SLOWDOWN_THRESHOLD {
+            flags.push(Flag::Slower { was: previous.time, ratio });
+        }
+    }
+    flags
+}
+
+/// The latest of `earlier` made under the same conditions as `current`, with
+/// the ratio of `current`'s time to its time.
+fn comparable_time<'a>(earlier: &[&'a Entry], current: &Entry) -> Option<(&'a Entry, f64)> {
+    let previous = earlier.iter().rev().find(|previous| previous.conditions == current.conditions)?;
+    Some((previous, current.time.as_secs_f64() / previous.time.as_secs_f64().max(1e-12)))
+}
+
+pub fn history_path() -> PathBuf {
+    match env::var_os(HISTORY_PATH_ENV) {
+        Some(path) if !path.is_empty() => PathBuf::from(path),
+        _ => PathBuf::from(DEFAULT_HISTORY_PATH),
+    }
+}
+
+/// One entry per part that produced an answer; days that failed are left out.
+pub fn entries(results: &[DayResult], commit: Option<&str>, timestamp: u64, conditions: Conditions) -> Vec<Entry> {
+    results
+        .iter()
+        .filter_map(|result| Some((result, result.outcome.as_ref().ok()?)))
+        .flat_map(|(result, parts)| {
+            parts.iter().map(move |part| Entry {
+                timestamp,
+                commit: commit.map(str::to_string),
+                year: result.year,
+                day: result.day,
+                part: part.part,
+                answer: part.answer.clone(),
+                time: part.time,
+                conditions: Some(conditions),
+            })
+        })
+        .collect()
+}
+
+/// Reads the whole history, oldest first; a missing file is an empty history.
+/// Lines that can't be read are skipped with a warning, so one bad line
+/// doesn't lose the rest of the history.
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Error reading history {}: {}", path.display(), e)),
+    };
+    Ok(text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(i, line)| match Entry::from_json(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warning!("Skipping {} line {}: {}", path.display(), i + 1, e);
+                None
+            }
+        })
+        .collect())
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| format!("Error creating {}: {}", dir.display(), e))?;
+    }
+    let lines: String = entries.iter().map(|entry| entry.to_json() + "\n").collect();
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| format!("Error writing history {}: {}", path.display(), e))
+}
+
+/// Appends the results to the history at `path` and returns each new entry
+/// with its flags against the earlier runs of that part.
+pub fn record(path: &Path, results: &[DayResult], conditions: Conditions) -> Result<Vec<(Entry, Vec<Flag>)>, String> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let new = entries(results, git_commit().as_deref(), timestamp, conditions);
+    if new.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let past = load(path)?;
+    append(path, &new)?;
+
+    let mut parts: BTreeMap<_, Vec<&Entry>> = BTreeMap::new();
+    for entry in &past {
+        parts.entry(entry.key()).or_default().push(entry);
+    }
+    Ok(new
+        .into_iter()
+        .map(|entry| {
+            let flags = parts.get(&entry.key()).map_or(Vec::new(), |earlier| compare(earlier, &entry));
+            (entry, flags)
+        })
+        .collect())
+}
+
+/// The commit the crate was run from, or `None` if git isn't available.
+pub fn git_commit() -> Option<String> {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let git = |args: &[&str]| {
+        let output = Command::new("git").arg("-C").arg(root).args(args).output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let hash = git(&["rev-parse", "--short", "HEAD"])?;
+    // Untracked files count too, since a new day's module starts out untracked
+    let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+    Some(if dirty { format!("{}-dirty", hash) } else { hash })
+}
+
+/// The last `limit` runs of every part in `entries`, grouped by part, with
+/// the time change against the previous run under the same conditions and
+/// flags against the runs before each. The second value is true if the
+/// latest run of any part is flagged.
+pub fn trend_table(entries: &[Entry], limit: usize) -> (String, bool) {
+    let mut parts: BTreeMap<(u16, u8, u8), Vec<&Entry>> = BTreeMap::new();
+    for entry in entries {
+        parts.entry(entry.key()).or_default().push(entry);
+    }
+
+    let mut out = String::new();
+    let mut flagged = false;
+    for ((year, day, part), runs) in parts {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let _ = writeln!(out, "{} day {} part {}", year, day, part);
+
+        let start = runs.len().saturating_sub(limit);
+        for i in start..runs.len() {
+            let run = runs[i];
+            let earlier = &runs[..i];
+            let change = comparable_time(earlier, run)
+                .map_or(String::new(), |(_, ratio)| format!("{:+.1}%", (ratio - 1.0) * 100.0));
+            let flags = compare(earlier, run);
+            flagged |= i + 1 == runs.len() && !flags.is_empty();
+
+            let answer = if run.answer.is_multiline() { "(grid)".to_string() } else { run.answer.to_string() };
+            let flags: Vec<String> = flags.iter().map(Flag::to_string).collect();
+            let line = format!(
+                "  {:<16}  {:<14} {:<16} {:>16} {:>10} {:>8}  {}",
+                format_timestamp(run.timestamp),
+                run.commit.as_deref().unwrap_or("-"),
+                run.conditions.map_or("-".to_string(), |conditions| conditions.to_string()),
+                answer,
+                format_duration(run.time),
+                change,
+                flags.join(", ")
+            );
+            let _ = writeln!(out, "{}", line.trim_end());
+        }
+    }
+    (out, flagged)
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, secs) = (timestamp / 86_400, timestamp % 86_400);
+    // Civil-from-days, counting from 0000-03-01 so leap days fall at the end
+    // of each year
+    let days = days as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, secs / 3600, secs % 3600 / 60)
+}
+
+enum Value {
+    String(String),
+    Number(u64),
+    Bool(bool),
+    Null,
+}
+
+fn number<T: TryFrom<u64>>(value: Value, name: &str) -> Result<T, String> {
+    match value {
+        Value::Number(n) => n.try_into().map_err(|_| format!("\"{}\" is out of range", name)),
+        _ => Err(format!("\"{}\" should be a number", name)),
+    }
+}
+
+/// Parses the flat objects [`Entry::to_json`] writes: string, unsigned
+/// integer, boolean and `null` values only.
+fn parse_object(line: &str) -> Result<BTreeMap<String, Value>, String> {
+    let mut chars = line.trim().chars().peekable();
+    let mut fields = BTreeMap::new();
+    let skip_spaces = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    };
+
+    if chars.next() != Some('{') {
+        return Err("expected a JSON object".to_string());
+    }
+    skip_spaces(&mut chars);
+    if chars.next_if_eq(&'}').is_some() {
+        return Ok(fields);
+    }
+    loop {
+        skip_spaces(&mut chars);
+        let key = parse_string(&mut chars)?;
+        skip_spaces(&mut chars);
+        if chars.next() != Some(':') {
+            return Err(format!("expected ':' after \"{}\"", key));
+        }
+        skip_spaces(&mut chars);
+        let value = match chars.peek() {
+            Some('"') => Value::String(parse_string(&mut chars)?),
+            Some('n' | 't' | 'f') => {
+                let mut word = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_alphabetic) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "null" => Value::Null,
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    _ => return Err(format!("bad value for \"{}\"", key)),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+                Value::Number(digits.parse().map_err(|_| format!("\"{}\" is out of range", key))?)
+            }
+            _ => return Err(format!("bad value for \"{}\"", key)),
+        };
+        fields.insert(key, value);
+
+        skip_spaces(&mut chars);
+        match chars.next() {
+            Some(',') => {}
+            Some('}') if chars.all(char::is_whitespace) => return Ok(fields),
+            _ => return Err("expected ',' or '}'".to_string()),
+        }
+    }
+}
+
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    if chars.next() != Some('"') {
+        return Err("expected a string".to_string());
+    }
+    let mut out = String::new();
+    loop {
+        match chars.next().ok_or("unterminated string")? {
+            '"' => return Ok(out),
+            '\\' => match chars.next().ok_or("unterminated string")? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&hex, 16).map_err(|_| format!("bad escape \\u{}", hex))?;
+                    out.push(char::from_u32(code).ok_or_else(|| format!("bad escape \\u{}", hex))?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{DayError, PartResult};
+    use crate::testing::TempDir;
+
+    fn entry(timestamp: u64, answer: i64, millis: u64) -> Entry {
+        Entry {
+            timestamp,
+            commit: Some("3f2a9c1".to_string()),
+            year: 2024,
+            day: 6,
+            part: 2,
+            answer: Answer::Int(answer),
+            time: Duration::from_millis(millis),
+            conditions: Some(Conditions { release: true, threaded: false }),
+        }
+    }
+
+    #[test]
+    fn test_json_round_trips() {
+        let line = entry(1_733_472_000, 1719, 1204).to_json();
+        assert_eq!(
+            line,
+            r#"{"timestamp": 1733472000, "commit": "3f2a9c1", "year": 2024, "day": 6, "part": 2, "answer": "1719", "kind": "int", "time_ns": 1204000000, "profile": "release", "threaded": false}"#
+        );
+        assert_eq!(Entry::from_json(&line), Ok(entry(1_733_472_000, 1719, 1204)));
+
+        // Entries from before conditions were recorded
+        let old = r#"{"timestamp": 1733472000, "commit": "3f2a9c1", "year": 2024, "day": 6, "part": 2, "answer": "1719", "time_ns": 1204000000}"#;
+        assert_eq!(Entry::from_json(old), Ok(Entry { conditions: None, ..entry(1_733_472_000, 1719, 1204) }));
+        assert!(Entry::from_json(&line.replace("false", "nope")).is_err());
+
+        // Every answer comes back exactly as it was
+        for answer in [
+            Answer::Grid("#.\n\"#".to_string()),
+            Answer::Text(String::new()),
+            Answer::Text("  padded \t".to_string()),
+            Answer::Text("42".to_string()),
+            Answer::BigInt(i128::MAX),
+        ] {
+            let entry = Entry { commit: None, answer, ..entry(0, 0, 1) };
+            assert_eq!(Entry::from_json(&entry.to_json()), Ok(entry));
+        }
+        assert!(Entry::from_json(&line.replace("\"int\"", "\"float\"")).is_err());
+        assert!(Entry::from_json(&line.replace("\"1719\"", "\"x\"")).is_err());
+
+        assert!(Entry::from_json("{\"timestamp\": 1}").unwrap_err().contains("missing"));
+        assert!(Entry::from_json("[1, 2]").is_err());
+        assert!(Entry::from_json(&line.replace("\"day\": 6", "\"day\": 600")).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(&[], &entry(1, 41, 110)), vec![]);
+        assert_eq!(compare(&[&entry(0, 41, 100)], &entry(1, 41, 110)), vec![]);
+        assert_eq!(
+            compare(&[&entry(0, 41, 100)], &entry(1, 42, 130)),
+            vec![Flag::AnswerChanged { was: Answer::Int(41) }, Flag::Slower { was: Duration::from_millis(100), ratio: 1.3 }]
+        );
+        // Too fast to tell
+        let fast = |micros| Entry { time: Duration::from_micros(micros), ..entry(0, 41, 0) };
+        assert_eq!(compare(&[&fast(10)], &fast(50)), vec![]);
+
+        // Timings are only compared with runs made the same way, answers with
+        // the latest run whatever it was
+        let made = |release, threaded, answer, millis| Entry {
+            conditions: Some(Conditions { release, threaded }),
+            ..entry(0, answer, millis)
+        };
+        let release = made(true, false, 41, 100);
+        let threaded = made(true, true, 42, 300);
+        assert_eq!(compare(&[&release], &made(false, false, 41, 1000)), vec![]);
+        assert_eq!(compare(&[&release, &threaded], &made(true, false, 42, 110)), vec![]);
+        assert_eq!(
+            compare(&[&release, &threaded], &made(true, false, 41, 200)),
+            vec![Flag::AnswerChanged { was: Answer::Int(42) }, Flag::Slower { was: Duration::from_millis(100), ratio: 2.0 }]
+        );
+        assert_eq!(Flag::Slower { was: Duration::from_millis(100), ratio: 1.3 }.to_string(), "30% slower (was 100.00ms)");
+    }
+
+    #[test]
+    fn test_record_flags_against_previous_run() {
+        let dir = TempDir::new("history");
+        let path = dir.join("history.jsonl");
+        let result = |answer: i64, millis: u64| DayResult {
+            year: 2024,
+            day: 6,
+            name: "Guard Gallivant",
+            parse_time: Duration::ZERO,
+            outcome: Ok(vec![PartResult { part: 2, answer: Answer::Int(answer), time: Duration::from_millis(millis), check: None }]),
+        };
+        let failed =
+            DayResult { outcome: Err(DayError::Timeout(Duration::from_secs(1))), day: 7, ..result(0, 0) };
+
+        let release = Conditions { release: true, threaded: false };
+        let first = record(&path, &[result(1719, 100), failed], release).unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(first[0].1.is_empty());
+
+        // A debug build is expected to be slower
+        let debug = record(&path, &[result(1719, 2000)], Conditions { release: false, ..release }).unwrap();
+        assert!(debug[0].1.is_empty(), "{:?}", debug);
+
+        let second = record(&path, &[result(1720, 200)], release).unwrap();
+        assert_eq!(second[0].1.len(), 2, "{:?}", second);
+
+        let history = load(&path).unwrap();
+        assert_eq!(history.len(), 3);
+        let (table, flagged) = trend_table(&history, 10);
+        assert!(flagged);
+        assert!(table.starts_with("2024 day 6 part 2\n"), "{}", table);
+        assert!(table.contains("+100.0%  answer changed (was 1719), 100% slower"), "{}", table);
+    }
+
+    #[test]
+    fn test_bad_lines_are_skipped() {
+        let dir = TempDir::new("history-bad-line");
+        let path = dir.join("history.jsonl");
+        let good = entry(1, 1719, 100).to_json();
+        // An empty answer as written before answer kinds were recorded
+        let unreadable = good.replace("\"1719\", \"kind\": \"int\"", "\"\"");
+        fs::write(&path, format!("{}\n{}\nnot json\n", unreadable, good)).unwrap();
+
+        assert_eq!(load(&path).unwrap(), vec![entry(1, 1719, 100)]);
+        append(&path, &[entry(2, 1719, 100)]).unwrap();
+        assert_eq!(load(&path).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_472_000 + 3_660), "2024-12-06 09:01");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
@@ -0,0 +1,457 @@
+// src/input.rs
+//! Puzzle input resolution. The input directory doubles as a cache: a day's
+//! `YYYY/dayXX.txt` is read from it when present, and otherwise downloaded through
+//! a [`Fetcher`] and saved there for next time.
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::log::{info, warning};
+
+/// Environment variable that overrides the directory holding `YYYY/dayXX.txt` files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "src/input";
+/// The only year inputs were kept for before they moved into per-year
+/// directories, as `dayXX.txt` directly in the input directory.
+const LEGACY_INPUT_YEAR: u16 = 2024;
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Overrides the server inputs are fetched from, e.g. a local mock.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Fetch missing inputs by copying them from this directory instead.
+pub const FETCH_DIR_ENV: &str = "AOC_FETCH_DIR";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `YYYY/dayXX.txt` in the input directory, fetched if missing.
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses the value given to `--input`, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// What to do when a day's input isn't in the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FetchPolicy {
+    Never,
+    /// Prompt on an interactive terminal, otherwise behave like `Never`.
+    #[default]
+    Ask,
+    Always,
+}
+
+/// Something that can download a day's puzzle input.
+pub trait Fetcher: Send + Sync {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// Downloads inputs from adventofcode.com (or a compatible server) using the
+/// session cookie. `https://` URLs go through `curl`; plain `http://` is
+/// handled in-process, which is what local mock servers use.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+    const USER_AGENT: &'static str = "github.com/50SACINMYSOCIDGAF/AdventOfCode2024";
+
+    pub fn new(session: impl Into<String>) -> Self {
+        HttpFetcher { base_url: Self::DEFAULT_BASE_URL.to_string(), session: session.into() }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Builds a fetcher from `AOC_SESSION` and `AOC_BASE_URL`, if a session is set.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var(SESSION_ENV).ok()?;
+        let session = session.trim();
+        if session.is_empty() {
+            return None;
+        }
+        let fetcher = HttpFetcher::new(session);
+        match env::var(BASE_URL_ENV) {
+            Ok(base_url) if !base_url.is_empty() => Some(fetcher.with_base_url(base_url)),
+            _ => Some(fetcher),
+        }
+    }
+
+    fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// The session goes to curl as a config file on stdin rather than as an
+    /// argument, where any local user could read it from the process list.
+    fn fetch_with_curl(&self, url: &str) -> Result<String, String> {
+        let mut child = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--user-agent", Self::USER_AGENT, "--config", "-"])
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Could not run curl: {}", e))?;
+
+        let config = format!("cookie = \"session={}\"\n", self.session.replace('\\', "\\\\").replace('"', "\\\""));
+        let written = child.stdin.take().map_or(Ok(()), |mut stdin| stdin.write_all(config.as_bytes()));
+        let output = child.wait_with_output().map_err(|e| format!("Could not run curl: {}", e))?;
+        written.map_err(|e| format!("Could not pass the session to curl: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("Fetching {} failed: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        String::from_utf8(output.stdout).map_err(|e| format!("Input from {} is not UTF-8: {}", url, e))
+    }
+
+    /// A bare-bones HTTP/1.0 client, so the server has no reason to send a
+    /// chunked body; one that does anyway is rejected rather than cached.
+    fn fetch_plain_http(&self, url: &str) -> Result<String, String> {
+        let rest = url.trim_start_matches("http://");
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let error = |e: io::Error| format!("Fetching {} failed: {}", url, e);
+
+        let mut stream = TcpStream::connect(host).map_err(error)?;
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n\r\n",
+            path, host, Self::USER_AGENT, self.session
+        )
+        .map_err(error)?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(error)?;
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| format!("Fetching {} failed: malformed response", url))?;
+        let mut lines = head.lines();
+        let status = lines.next().unwrap_or_default();
+        if status.split_whitespace().nth(1) != Some("200") {
+            return Err(format!("Fetching {} failed: {}", url, status));
+        }
+
+        let headers: Vec<(String, &str)> = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim()))
+            .collect();
+        let header = |name: &str| headers.iter().find(|(n, _)| n == name).map(|(_, value)| *value);
+        if let Some(encoding) = header("transfer-encoding").filter(|e| !e.eq_ignore_ascii_case("identity")) {
+            return Err(format!("Fetching {} failed: unsupported Transfer-Encoding {}", url, encoding));
+        }
+        match header("content-length").map(str::parse::<usize>) {
+            None => Ok(body.to_string()),
+            Some(Ok(length)) if length == body.len() => Ok(body.to_string()),
+            Some(Ok(length)) => Err(format!(
+                "Fetching {} failed: expected {} bytes but got {}",
+                url, length, body.len()
+            )),
+            Some(Err(_)) => Err(format!("Fetching {} failed: invalid Content-Length", url)),
+        }
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day);
+        info!("Fetching {}", url);
+        if url.starts_with("http://") {
+            self.fetch_plain_http(&url)
+        } else {
+            self.fetch_with_curl(&url)
+        }
+    }
+}
+
+/// Copies inputs from another directory laid out as `YYYY/dayXX.txt`, e.g. a
+/// shared team folder.
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        DirFetcher { dir: dir.into() }
+    }
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = day_input_path(&self.dir, year, day);
+        fs::read_to_string(&path).map_err(|e| format!("Error reading {}: {}", path.display(), e))
+    }
+}
+
+/// Resolves inputs from the cache directory, fetching missing ones.
+pub struct InputManager {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+    policy: FetchPolicy,
+}
+
+impl InputManager {
+    pub fn new(cache_dir: impl Into<PathBuf>, fetcher: Option<Box<dyn Fetcher>>, policy: FetchPolicy) -> Self {
+        InputManager { cache_dir: cache_dir.into(), fetcher, policy }
+    }
+
+    /// The input directory from `AOC_INPUT_DIR`, fetching from `AOC_FETCH_DIR`
+    /// if set, otherwise from adventofcode.com when `AOC_SESSION` is set.
+    pub fn from_env(policy: FetchPolicy) -> Self {
+        let fetcher: Option<Box<dyn Fetcher>> = match env::var_os(FETCH_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => Some(Box::new(DirFetcher::new(dir))),
+            _ => HttpFetcher::from_env().map(|f| Box::new(f) as Box<dyn Fetcher>),
+        };
+        InputManager::new(input_dir(), fetcher, policy)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        day_input_path(&self.cache_dir, year, day)
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            // An empty file is a placeholder left by `new`, not a real input
+            Ok(input) if !input.trim().is_empty() => return Ok(input),
+            Ok(_) => {}
+            Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                return Err(format!("Error reading input file for day {}: {}", day, e));
+            }
+            Err(_) => {}
+        }
+        if let Some(input) = self.load_legacy(year, day, &path) {
+            return Ok(input);
+        }
+
+        let fetcher = match &self.fetcher {
+            Some(fetcher) if self.should_fetch(day) => fetcher,
+            _ => return Err(self.missing_message(day, &path)),
+        };
+
+        let input = fetcher.fetch(year, day)?;
+        fs::create_dir_all(path.parent().unwrap_or(&self.cache_dir))
+            .and_then(|()| fs::write(&path, &input))
+            .map_err(|e| format!("Fetched day {} but could not cache it at {}: {}", day, path.display(), e))?;
+        info!("Cached day {} input at {}", day, path.display());
+        Ok(input)
+    }
+
+    /// Reads an input still at its old `dayXX.txt` location, asking for it to
+    /// be moved to `path`.
+    fn load_legacy(&self, year: u16, day: u8, path: &Path) -> Option<String> {
+        if year != LEGACY_INPUT_YEAR {
+            return None;
+        }
+        let legacy = self.cache_dir.join(format!("day{:02}.txt", day));
+        let input = fs::read_to_string(&legacy).ok().filter(|input| !input.trim().is_empty())?;
+        warning!(
+            "Read day {} input from {}; inputs now live in per-year directories, move it to {}",
+            day, legacy.display(), path.display()
+        );
+        Some(input)
+    }
+
+    fn should_fetch(&self, day: u8) -> bool {
+        match self.policy {
+            FetchPolicy::Never => false,
+            FetchPolicy::Always => true,
+            FetchPolicy::Ask => {
+                io::stdin().is_terminal() && confirm(&format!("Input for day {} is missing. Fetch it?", day))
+            }
+        }
+    }
+
+    fn missing_message(&self, day: u8, path: &Path) -> String {
+        let hint = if self.fetcher.is_some() {
+            "pass --fetch to download it"
+        } else {
+            "set AOC_SESSION (or AOC_FETCH_DIR) and pass --fetch to download it"
+        };
+        format!(
+            "Error reading input file for day {}: {} is missing or empty (inputs live in YYYY/dayXX.txt)\nSet {} / pass --input, or {}",
+            day, path.display(), INPUT_DIR_ENV, hint
+        )
+    }
+}
+
+fn confirm(question: &str) -> bool {
+    eprint!("{} [y/N] ", question);
+    let _ = io::stderr().flush();
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
+
+pub fn input_dir() -> PathBuf {
+    resolve_input_dir(env::var_os(INPUT_DIR_ENV).map(PathBuf::from))
+}
+
+fn resolve_input_dir(from_env: Option<PathBuf>) -> PathBuf {
+    match from_env {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => PathBuf::from(DEFAULT_INPUT_DIR),
+    }
+}
+
+pub fn day_input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+pub fn read_input(year: u16, day: u8, source: &InputSource, policy: FetchPolicy) -> Result<String, String> {
+    match source {
+        InputSource::Default => InputManager::from_env(policy).load(year, day),
+        InputSource::File(path) => fs::read_to_string(path)
+            .map_err(|e| format!("Error reading input file {}: {}", path.display(), e)),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Error reading input from stdin: {}", e))?;
+            Ok(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use crate::testing::TempDir;
+
+    struct MockFetcher {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Fetcher for MockFetcher {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(format!("input for {} day {}\n", year, day))
+        }
+    }
+
+    #[test]
+    fn test_input_dir_resolution() {
+        assert_eq!(resolve_input_dir(None), PathBuf::from("src/input"));
+        assert_eq!(resolve_input_dir(Some(PathBuf::new())), PathBuf::from("src/input"));
+        assert_eq!(resolve_input_dir(Some(PathBuf::from("/tmp/aoc"))), PathBuf::from("/tmp/aoc"));
+        assert_eq!(day_input_path(Path::new("inputs"), 2024, 6), PathBuf::from("inputs/2024/day06.txt"));
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("alt.txt"), InputSource::File(PathBuf::from("alt.txt")));
+    }
+
+    #[test]
+    fn test_manager_fetches_once_then_uses_cache() {
+        let cache = TempDir::new("input-cache");
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fetcher = MockFetcher { calls: Arc::clone(&calls) };
+        let manager = InputManager::new(&*cache, Some(Box::new(fetcher)), FetchPolicy::Always);
+
+        assert_eq!(manager.load(2024, 7).unwrap(), "input for 2024 day 7\n");
+        assert_eq!(manager.load(2024, 7).unwrap(), "input for 2024 day 7\n");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(manager.path(2024, 7).exists());
+    }
+
+    #[test]
+    fn test_manager_respects_policy() {
+        let cache = TempDir::new("input-never");
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fetcher = MockFetcher { calls: Arc::clone(&calls) };
+        let manager = InputManager::new(&*cache, Some(Box::new(fetcher)), FetchPolicy::Never);
+
+        let err = manager.load(2024, 3).unwrap_err();
+        assert!(err.contains("pass --fetch"), "{}", err);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_manager_reads_legacy_location() {
+        let cache = TempDir::new("input-legacy");
+        fs::write(cache.join("day04.txt"), "XMAS\n").unwrap();
+        let manager = InputManager::new(&*cache, None, FetchPolicy::Never);
+
+        assert_eq!(manager.load(2024, 4).unwrap(), "XMAS\n");
+        assert!(manager.load(2015, 4).is_err());
+    }
+
+    #[test]
+    fn test_dir_fetcher() {
+        let shared = TempDir::new("input-shared");
+        let path = day_input_path(&shared, 2024, 2);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "7 6 4 2 1\n").unwrap();
+
+        let cache = TempDir::new("input-from-shared");
+        let manager = InputManager::new(&*cache, Some(Box::new(DirFetcher::new(&*shared))), FetchPolicy::Always);
+        assert_eq!(manager.load(2024, 2).unwrap(), "7 6 4 2 1\n");
+        assert!(manager.load(2024, 3).is_err());
+    }
+
+    /// Answers a single request on a local port with `response`. Returns the
+    /// base URL to fetch from and a handle that yields the request received.
+    fn mock_server(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (format!("http://{}/", addr), server)
+    }
+
+    #[test]
+    fn test_http_fetcher_against_mock_server() {
+        let (base_url, server) = mock_server("HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n3   4\n");
+        let fetcher = HttpFetcher::new("abc123").with_base_url(base_url);
+        assert_eq!(fetcher.fetch(2024, 1).unwrap(), "3   4\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.0\r\n"), "{}", request);
+        assert!(request.contains("Cookie: session=abc123\r\n"), "{}", request);
+    }
+
+    #[test]
+    fn test_http_fetcher_rejects_chunked_and_truncated_bodies() {
+        for (response, expected) in [
+            ("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n6\r\n3   4\n\r\n0\r\n\r\n", "Transfer-Encoding"),
+            ("HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n3   4\n", "expected 10 bytes"),
+        ] {
+            let (base_url, server) = mock_server(response);
+            let err = HttpFetcher::new("abc123").with_base_url(base_url).fetch(2024, 1).unwrap_err();
+            assert!(err.contains(expected), "{}", err);
+            server.join().unwrap();
+        }
+    }
+}
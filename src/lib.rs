@@ -0,0 +1,25 @@
+// src/lib.rs
+//! Advent of Code solutions and the machinery to run them. The `AdventOfCode`
+//! binary is a thin command line over this crate; integration tests, benches
+//! and other tools can link against it directly.
+//!
+//! Start from [`solutions::calendars`] (or [`solutions::latest`]) to find a
+//! day, and [`runner::run_day`] to run it.
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod log;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod solutions;
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,174 @@
+// src/log.rs
+//! Minimal leveled logging to stderr, so stdout carries nothing but answers.
+//!
+//! Solutions log through the `error!`, `warning!`, `info!`, `debug!` and `trace!` macros and report long
+//! loops with [`Progress`]; the runner picks the level from `-q`/`-v`.
+
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    /// Level for a verbosity count: `-q` is -1, nothing is 0, `-vv` is 2.
+    pub fn from_verbosity(verbosity: i8) -> Level {
+        let index = (Level::Warn as i8 + verbosity).clamp(0, Level::Trace as i8);
+        Level::ALL[index as usize]
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        f.write_str(name)
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::ALL[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+#[doc(hidden)]
+pub fn log(level: Level, args: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("[{}] {}", level, args);
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_error {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Error, format_args!($($arg)*)) };
+}
+
+// `warn` would clash with the built-in lint attribute
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_warning {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Warn, format_args!($($arg)*)) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_info {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Info, format_args!($($arg)*)) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_debug {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*)) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_trace {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Trace, format_args!($($arg)*)) };
+}
+
+// Exported under private names so the public paths are `log::debug!` etc.
+pub use crate::{
+    __log_debug as debug, __log_error as error, __log_info as info, __log_trace as trace, __log_warning as warning,
+};
+
+/// Progress of a long loop, reported on stderr at `info` level.
+///
+/// On a terminal the line is redrawn in place every percent; otherwise a
+/// line is written every ten percent so logs stay short.
+pub struct Progress {
+    label: &'static str,
+    total: usize,
+    done: usize,
+    next_report: usize,
+    step: usize,
+    interactive: bool,
+    started: Instant,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: usize) -> Self {
+        let interactive = io::stderr().is_terminal();
+        let step = (total / if interactive { 100 } else { 10 }).max(1);
+        Progress { label, total, done: 0, next_report: step, step, interactive, started: Instant::now() }
+    }
+
+    pub fn tick(&mut self) {
+        self.inc(1);
+    }
+
+    pub fn inc(&mut self, n: usize) {
+        self.done += n;
+        if self.done >= self.next_report && enabled(Level::Info) {
+            self.next_report = self.done + self.step;
+            self.report();
+        }
+    }
+
+    fn report(&self) {
+        let percent = self.done as f64 / self.total.max(1) as f64 * 100.0;
+        let line = format!("[info] {}: {}/{} ({:.0}%)", self.label, self.done, self.total, percent);
+        if self.interactive {
+            eprint!("\r{}", line);
+            let _ = io::stderr().flush();
+        } else {
+            eprintln!("{}", line);
+        }
+    }
+
+    pub fn finish(self) {
+        if !enabled(Level::Info) {
+            return;
+        }
+        if self.interactive {
+            eprint!("\r\x1b[2K");
+        }
+        eprintln!("[info] {}: done {} in {:.2?}", self.label, self.total, self.started.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_verbosity() {
+        assert_eq!(Level::from_verbosity(-1), Level::Error);
+        assert_eq!(Level::from_verbosity(-5), Level::Error);
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(9), Level::Trace);
+    }
+
+    #[test]
+    fn test_level_ordering() {
+        assert!(Level::Error < Level::Warn);
+        assert_eq!(Level::Debug.to_string(), "debug");
+    }
+}
@@ -1,76 +1,270 @@
 // src/main.rs
 use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
 
-mod solutions {
-    pub mod s01;
-    pub mod s02;
-    pub mod s03;
-    pub mod s04;
-    pub mod s05;
-    pub mod s06;
-    // Future days will be added here
+use advent_of_code::input::{FetchPolicy, InputSource};
+use advent_of_code::log::{self, error, warning};
+use advent_of_code::report::{self, Format};
+use advent_of_code::runner::{self, DayResult};
+use advent_of_code::solution::Solution;
+use advent_of_code::solutions::y2024::d06::{self, Day06};
+use advent_of_code::solutions::{self, Calendar};
+use advent_of_code::{bench, history, input, scaffold};
+use cli::{Command, HistoryOptions, RenderOptions, RunOptions};
+
+mod cli;
+#[cfg(test)]
+mod testing;
+mod watch;
+
+fn main() -> ExitCode {
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::New { year, day, name }) => {
+            let year = year.unwrap_or_else(|| solutions::latest().year());
+            return new_day(year, day, name.as_deref());
+        }
+        Ok(Command::History(options)) => return show_history(&options),
+        Ok(Command::Render(options)) => return render(&options),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    log::set_level(log::Level::from_verbosity(options.verbosity));
+
+    let calendar = match options.year {
+        Some(year) => match solutions::calendar(year) {
+            Some(calendar) => calendar,
+            None => {
+                let years: Vec<String> = solutions::calendars().iter().map(|c| c.year().to_string()).collect();
+                eprintln!("No solutions for {} yet (available: {})", year, years.join(", "));
+                return ExitCode::from(2);
+            }
+        },
+        None => solutions::latest(),
+    };
+
+    let available: Vec<u8> = calendar.all().iter().map(|s| s.day()).collect();
+    let days = match cli::resolve_days(&options.days, &available) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    if options.bench {
+        return run_benches(calendar, &days, &options);
+    }
+    if options.watch {
+        let solution = calendar.get(days[0]).expect("resolve_days only returns registered days");
+        return watch::watch(calendar.year(), solution, &options);
+    }
+
+    let solutions: Vec<_> = days
+        .iter()
+        .map(|&day| calendar.get(day).expect("resolve_days only returns registered days"))
+        .collect();
+    let config = options.run_config();
+    let show = |result: &DayResult| {
+        if let Some(message) = report::error_message(result) {
+            error!("{}", message);
+        }
+        if options.format == Format::Text {
+            report::print_text(result);
+        }
+    };
+
+    let results = match options.jobs {
+        Some(jobs) => {
+            let results = runner::run_days(calendar.year(), &solutions, &config, jobs);
+            results.iter().for_each(show);
+            results
+        }
+        None => solutions
+            .iter()
+            .map(|solution| {
+                let result = runner::run_day(calendar.year(), *solution, &config);
+                show(&result);
+                result
+            })
+            .collect(),
+    };
+
+    if options.history && options.input == InputSource::Default {
+        record_history(&results, options.jobs.is_some() || options.timeout.is_some());
+    }
+
+    if options.format == Format::Text && options.jobs.is_some() {
+        println!();
+        report::print_summary(&results);
+    }
+    match options.format {
+        Format::Text if options.check => {
+            println!();
+            report::print_check_summary(&results);
+        }
+        Format::Text => {}
+        Format::Json => println!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+
+    if results.iter().any(DayResult::is_failure) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() != 2 {
-        println!("Please provide the day number as an argument (1-25)");
-        return;
+fn new_day(year: u16, day: u8, name: Option<&str>) -> ExitCode {
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day, name) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("{}", e);
+            ExitCode::FAILURE
+        }
     }
+}
 
-    let day = match args[1].parse::<u8>() {
-        Ok(n) if (1..=25).contains(&n) => n,
-        _ => {
-            println!("Please provide a valid day number between 1 and 25");
-            return;
+/// Appends the answers to the history and warns about anything that changed
+/// or slowed down since the previous run. `threaded` says whether the days
+/// ran on their own threads.
+fn record_history(results: &[DayResult], threaded: bool) {
+    match history::record(&history::history_path(), results, history::Conditions::current(threaded)) {
+        Ok(recorded) => {
+            for (entry, flags) in recorded {
+                for flag in flags {
+                    warning!("{} day {} part {}: {}", entry.year, entry.day, entry.part, flag);
+                }
+            }
+        }
+        Err(e) => warning!("Not recorded in the history: {}", e),
+    }
+}
+
+fn show_history(options: &HistoryOptions) -> ExitCode {
+    let path = history::history_path();
+    let entries = match history::load(&path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!("{}", e);
+            return ExitCode::FAILURE;
         }
     };
 
-    match run_day(day) {
-        Ok(()) => (),
-        Err(e) => println!("{}", e),
+    let every_day: Vec<u8> = (1..=25).collect();
+    let days = match options.days.as_slice() {
+        [] => every_day.clone(),
+        specs => cli::resolve_days(specs, &every_day).expect("every day is available"),
+    };
+    let entries: Vec<_> = entries
+        .into_iter()
+        .filter(|entry| options.year.is_none_or(|year| entry.year == year))
+        .filter(|entry| options.part.is_none_or(|part| entry.part == part) && days.contains(&entry.day))
+        .collect();
+    if entries.is_empty() {
+        println!("No matching runs recorded in {}", path.display());
+        return ExitCode::SUCCESS;
+    }
+
+    let (table, flagged) = history::trend_table(&entries, options.limit);
+    print!("{}", table);
+    if flagged {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
     }
 }
 
-fn run_day(day: u8) -> Result<(), String> {
-    // Try to read the input file
-    let input = fs::read_to_string(format!("src/input/day{:02}.txt", day))
-        .map_err(|e| format!("Error reading input file for day {}: {}\nMake sure src/input/day{:02}.txt exists!", day, e, day))?;
-
-    // Try to run the solution for the given day
-    match day {
-        1 => {
-            let (part1, part2) = solutions::s01::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        2 => {
-            let (part1, part2) = solutions::s02::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        3 => {
-            let (part1, part2) = solutions::s03::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        4 => {
-            let (part1, part2) = solutions::s04::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        5 => {
-            let (part1, part2) = solutions::s05::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        6 => {
-            let (part1, part2) = solutions::s06::solve_both(&input);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
-        },
-        d => return Err(format!("Day {} not implemented yet", d)),
+fn render(options: &RenderOptions) -> ExitCode {
+    let year = options.year.unwrap_or_else(|| solutions::latest().year());
+    if (year, options.day) != (2024, 6) {
+        eprintln!("Only 2024 day 6 can be rendered so far");
+        return ExitCode::from(2);
+    }
+
+    let patrol = match input::read_input(year, options.day, &options.input, FetchPolicy::Ask)
+        .and_then(|input| Day06.parse(&input).map_err(|e| format!("Day {}: {}", options.day, e)))
+    {
+        Ok(patrol) => patrol,
+        Err(e) => {
+            error!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut images = Vec::new();
+    if let Some(path) = &options.svg {
+        images.push((path, d06::render::to_svg(&patrol).into_bytes()));
+    }
+    if let Some(path) = &options.ppm {
+        images.push((path, d06::render::to_ppm(&patrol, options.scale)));
+    }
+    for (path, image) in &images {
+        match fs::write(path, image) {
+            Ok(()) => println!("Wrote {}", path.display()),
+            Err(e) => {
+                error!("Error writing {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
     }
 
-    Ok(())
-}
\ No newline at end of file
+    if options.animate {
+        if let Err(e) = d06::render::animate(&patrol, &mut io::stdout().lock(), options.delay, options.every) {
+            error!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    } else if images.is_empty() {
+        println!("{}", d06::render::route(&patrol));
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_benches(calendar: &Calendar, days: &[u8], options: &RunOptions) -> ExitCode {
+    let mut failed = false;
+    let mut benches = Vec::new();
+    for &day in days {
+        match bench_day(calendar, day, options) {
+            Ok(result) => benches.push(result),
+            Err(e) => {
+                error!("{}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if benches.len() > 1 {
+        println!();
+        bench::print_summary(&benches);
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench_day(calendar: &Calendar, day: u8, options: &RunOptions) -> Result<bench::DayBench, String> {
+    let solution = calendar.get(day).ok_or_else(|| format!("Day {} not implemented yet", day))?;
+    let input = input::read_input(calendar.year(), day, &options.input, options.fetch)?;
+
+    let result = bench::bench_day(solution, &input, options.part, options.iterations)
+        .map_err(|e| format!("Day {}: {}", day, e))?;
+    bench::print_day(&result, options.iterations);
+    Ok(result)
+}
@@ -0,0 +1,345 @@
+// src/report.rs
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answers::CheckStatus;
+use crate::bench::format_duration;
+use crate::runner::{DayError, DayResult, PartResult};
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Part 1: ...` lines for people.
+    #[default]
+    Text,
+    /// A JSON array with one object per part.
+    Json,
+    /// A header line and one row per part.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format '{}', expected text, json or csv", s)),
+        }
+    }
+}
+
+pub fn print_text(result: &DayResult) {
+    let Ok(parts) = &result.outcome else {
+        return;
+    };
+    println!("Day {}: {}", result.day, result.name);
+    for part in parts {
+        let status = part.check.as_ref().map_or(String::new(), |status| format!(" [{}]", status));
+        if part.answer.is_multiline() {
+            println!("Part {}:{}\n{}", part.part, status, part.answer);
+        } else {
+            println!("Part {}: {}{}", part.part, part.answer, status);
+        }
+    }
+}
+
+/// Why a day failed, naming the day so it can be told apart from the others
+/// in a multi-day run; `None` if it ran.
+pub fn error_message(result: &DayResult) -> Option<String> {
+    result.outcome.as_ref().err().map(|e| format!("Day {}: {}", result.day, e))
+}
+
+/// Prints `N passed, N failed, N missing` over every checked part.
+pub fn print_check_summary(results: &[DayResult]) {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for part in results.iter().filter_map(|r| r.outcome.as_ref().ok()).flatten() {
+        match part.check {
+            Some(CheckStatus::Pass) => passed += 1,
+            Some(CheckStatus::Fail { .. }) => failed += 1,
+            Some(CheckStatus::Missing) => missing += 1,
+            None => {}
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+}
+
+/// Prints one row per day (answers, total time and status) and a total line.
+pub fn print_summary(results: &[DayResult]) {
+    print!("{}", summary_table(results));
+}
+
+fn summary_table(results: &[DayResult]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{:<5} {:<24} {:<16} {:<16} {:>10}  Status", "Day", "Name", "Part 1", "Part 2", "Time");
+
+    let mut total = Duration::ZERO;
+    let mut failed = 0;
+    for result in results {
+        total += result.total_time();
+        failed += usize::from(result.is_failure());
+
+        let (part1, part2, status) = match &result.outcome {
+            Ok(parts) => {
+                let answer = |n: u8| {
+                    parts.iter().find(|p| p.part == n).map_or("-".to_string(), |p| {
+                        if p.answer.is_multiline() {
+                            "(grid)".to_string()
+                        } else {
+                            p.answer.to_string()
+                        }
+                    })
+                };
+                (answer(1), answer(2), day_status(parts).to_string())
+            }
+            Err(DayError::Failed(e)) => {
+                ("-".to_string(), "-".to_string(), format!("error: {}", e.lines().next().unwrap_or_default()))
+            }
+            Err(e) => ("-".to_string(), "-".to_string(), e.to_string()),
+        };
+        let _ = writeln!(
+            out,
+            "{:<5} {:<24} {:<16} {:<16} {:>10}  {}",
+            result.day,
+            result.name,
+            part1,
+            part2,
+            format_duration(result.total_time()),
+            status
+        );
+    }
+
+    let status = if failed == 0 { "ok".to_string() } else { format!("{} failed", failed) };
+    let _ = writeln!(out, "{:<5} {:<24} {:<16} {:<16} {:>10}  {}", "Total", "", "", "", format_duration(total), status);
+    out
+}
+
+/// The worst status across a day's parts: fail, then missing, then pass.
+fn day_status(parts: &[PartResult]) -> &'static str {
+    let statuses: Vec<&str> = parts.iter().map(status).collect();
+    ["fail", "missing", "pass"].into_iter().find(|s| statuses.contains(s)).unwrap_or("ok")
+}
+
+fn error_status(error: &DayError) -> &'static str {
+    match error {
+        DayError::Failed(_) => "error",
+        DayError::Timeout(_) => "timeout",
+        DayError::Panic(_) => "panic",
+    }
+}
+
+fn status(part: &PartResult) -> &'static str {
+    match part.check {
+        None => "ok",
+        Some(CheckStatus::Pass) => "pass",
+        Some(CheckStatus::Fail { .. }) => "fail",
+        Some(CheckStatus::Missing) => "missing",
+    }
+}
+
+/// Renders results as a JSON array. Answers are always strings so big
+/// integers and multi-line grids survive intact; a day that couldn't run is a
+/// single entry with `"part": null` and an `"error"` message.
+pub fn to_json(results: &[DayResult]) -> String {
+    let mut entries = Vec::new();
+    for result in results {
+        match &result.outcome {
+            Ok(parts) => {
+                for part in parts {
+                    let mut entry = format!(
+                        "{{\"year\": {}, \"day\": {}, \"name\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"time_ns\": {}, \"status\": \"{}\"",
+                        result.year,
+                        result.day,
+                        json_string(result.name),
+                        part.part,
+                        json_string(&part.answer.to_string()),
+                        result.parse_time.as_nanos(),
+                        part.time.as_nanos(),
+                        status(part)
+                    );
+                    if let Some(CheckStatus::Fail { expected }) = &part.check {
+                        let _ = write!(entry, ", \"expected\": {}", json_string(&expected.to_string()));
+                    }
+                    entry.push('}');
+                    entries.push(entry);
+                }
+            }
+            Err(e) => entries.push(format!(
+                "{{\"year\": {}, \"day\": {}, \"name\": {}, \"part\": null, \"answer\": null, \"status\": \"{}\", \"error\": {}}}",
+                result.year,
+                result.day,
+                json_string(result.name),
+                error_status(e),
+                json_string(&e.to_string())
+            )),
+        }
+    }
+
+    if entries.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n  {}\n]", entries.join(",\n  "))
+    }
+}
+
+/// Renders results as CSV with a header row; times are in nanoseconds.
+pub fn to_csv(results: &[DayResult]) -> String {
+    let mut out = String::from("year,day,name,part,answer,parse_ns,time_ns,status,expected\n");
+    for result in results {
+        match &result.outcome {
+            Ok(parts) => {
+                for part in parts {
+                    let expected = match &part.check {
+                        Some(CheckStatus::Fail { expected }) => expected.to_string(),
+                        _ => String::new(),
+                    };
+                    let _ = writeln!(
+                        out,
+                        "{},{},{},{},{},{},{},{},{}",
+                        result.year,
+                        result.day,
+                        csv_field(result.name),
+                        part.part,
+                        csv_field(&part.answer.to_string()),
+                        result.parse_time.as_nanos(),
+                        part.time.as_nanos(),
+                        status(part),
+                        csv_field(&expected)
+                    );
+                }
+            }
+            Err(e) => {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},,,,,{},{}",
+                    result.year,
+                    result.day,
+                    csv_field(result.name),
+                    error_status(e),
+                    csv_field(&e.to_string())
+                );
+            }
+        }
+    }
+    out
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                year: 2024,
+                day: 5,
+                name: "Print Queue",
+                parse_time: Duration::from_nanos(1500),
+                outcome: Ok(vec![
+                    PartResult { part: 1, answer: Answer::Int(143), time: Duration::from_nanos(200), check: Some(CheckStatus::Pass) },
+                    PartResult {
+                        part: 2,
+                        answer: Answer::Int(120),
+                        time: Duration::from_nanos(300),
+                        check: Some(CheckStatus::Fail { expected: Answer::Int(123) }),
+                    },
+                ]),
+            },
+            DayResult {
+                year: 2024,
+                day: 6,
+                name: "Guard Gallivant",
+                parse_time: Duration::ZERO,
+                outcome: Err(DayError::Failed("no \"input\"".to_string())),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&results()),
+            r#"[
+  {"year": 2024, "day": 5, "name": "Print Queue", "part": 1, "answer": "143", "parse_ns": 1500, "time_ns": 200, "status": "pass"},
+  {"year": 2024, "day": 5, "name": "Print Queue", "part": 2, "answer": "120", "parse_ns": 1500, "time_ns": 300, "status": "fail", "expected": "123"},
+  {"year": 2024, "day": 6, "name": "Guard Gallivant", "part": null, "answer": null, "status": "error", "error": "no \"input\""}
+]"#
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,name,part,answer,parse_ns,time_ns,status,expected\n\
+             2024,5,Print Queue,1,143,1500,200,pass,\n\
+             2024,5,Print Queue,2,120,1500,300,fail,123\n\
+             2024,6,Guard Gallivant,,,,,error,\"no \"\"input\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let table = summary_table(&results());
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Day   Name"));
+        assert!(lines[1].starts_with("5     Print Queue              143              120"), "{}", lines[1]);
+        assert!(lines[1].ends_with("2.00µs  fail"), "{}", lines[1]);
+        assert!(lines[2].ends_with("error: no \"input\""), "{}", lines[2]);
+        assert!(lines[3].starts_with("Total") && lines[3].ends_with("2.00µs  2 failed"), "{}", lines[3]);
+    }
+
+    #[test]
+    fn test_error_message() {
+        let results = results();
+        assert_eq!(error_message(&results[0]), None);
+        assert_eq!(error_message(&results[1]).as_deref(), Some("Day 6: no \"input\""));
+
+        let timeout = DayResult { outcome: Err(DayError::Timeout(Duration::from_millis(10))), ..results[1].clone() };
+        assert_eq!(error_message(&timeout).as_deref(), Some("Day 6: TIMEOUT after 10.00ms"));
+        let panic = DayResult { outcome: Err(DayError::Panic("boom".to_string())), ..results[1].clone() };
+        assert_eq!(error_message(&panic).as_deref(), Some("Day 6: PANIC: boom"));
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+        assert_eq!(Format::from_str("json"), Ok(Format::Json));
+        assert!(Format::from_str("xml").is_err());
+    }
+}
@@ -0,0 +1,332 @@
+// src/runner.rs
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::answers::{self, CheckStatus};
+use crate::bench::format_duration;
+use crate::input::{self, FetchPolicy, InputSource};
+use crate::log::info;
+use crate::solution::Runner;
+
+/// One part's answer, how long it took, and how it compared to the answers store.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+    /// `None` unless the run was made with `--check`.
+    pub check: Option<CheckStatus>,
+}
+
+/// Why a day produced no answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayError {
+    /// The input, its parse or the answers file was bad.
+    Failed(String),
+    /// The day was still running when the time limit ran out.
+    Timeout(Duration),
+    Panic(String),
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DayError::Failed(message) => f.write_str(message),
+            DayError::Timeout(limit) => write!(f, "TIMEOUT after {}", format_duration(*limit)),
+            DayError::Panic(message) => write!(f, "PANIC: {}", message),
+        }
+    }
+}
+
+/// Everything the runner learned about one day.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub parse_time: Duration,
+    /// The parts that ran, or why the day couldn't run at all.
+    pub outcome: Result<Vec<PartResult>, DayError>,
+}
+
+impl DayResult {
+    /// True if the day errored or any checked answer didn't match.
+    pub fn is_failure(&self) -> bool {
+        match &self.outcome {
+            Ok(parts) => parts.iter().any(|p| matches!(p.check, Some(CheckStatus::Fail { .. }))),
+            Err(_) => true,
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        let parts = self.outcome.as_ref().map_or(Duration::ZERO, |parts| parts.iter().map(|p| p.time).sum());
+        self.parse_time + parts
+    }
+}
+
+/// How each day is run: where its input comes from, which parts, whether to
+/// check the answers and how long it may take.
+#[derive(Debug, Clone, Default)]
+pub struct RunConfig {
+    pub input: InputSource,
+    pub fetch: FetchPolicy,
+    pub part: Option<u8>,
+    pub check: bool,
+    pub timeout: Option<Duration>,
+}
+
+pub fn selected_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// Reads the input, parses it and runs the selected parts, timing each step.
+///
+/// A panic in the solution is caught and reported as [`DayError::Panic`]. With
+/// a timeout the day runs on its own thread; one that overruns is abandoned
+/// (it keeps running in the background until the process exits).
+pub fn run_day(year: u16, solution: &'static dyn Runner, config: &RunConfig) -> DayResult {
+    let day = solution.day();
+    let mut result = DayResult { year, day, name: solution.name(), parse_time: Duration::ZERO, outcome: Ok(Vec::new()) };
+
+    let input = match input::read_input(year, day, &config.input, config.fetch) {
+        Ok(input) => input,
+        Err(e) => {
+            result.outcome = Err(DayError::Failed(e));
+            return result;
+        }
+    };
+    let (part, check) = (config.part, config.check);
+    let solved = match config.timeout {
+        None => solve_isolated(year, solution, &input, part, check),
+        Some(limit) => {
+            let (sender, receiver) = mpsc::channel();
+            let spawned = thread::Builder::new()
+                .name(format!("day{:02}", day))
+                .spawn(move || sender.send(solve_isolated(year, solution, &input, part, check)));
+            match spawned {
+                Ok(_) => match receiver.recv_timeout(limit) {
+                    Ok(solved) => solved,
+                    Err(RecvTimeoutError::Timeout) => Err(DayError::Timeout(limit)),
+                    Err(RecvTimeoutError::Disconnected) => Err(DayError::Panic("worker thread died".to_string())),
+                },
+                Err(e) => Err(DayError::Failed(format!("Could not start a thread for day {}: {}", day, e))),
+            }
+        }
+    };
+    match solved {
+        Ok((parse_time, parts)) => {
+            result.parse_time = parse_time;
+            result.outcome = Ok(parts);
+        }
+        Err(e) => result.outcome = Err(e),
+    }
+    info!("Day {} solved in {:.2?}", day, result.total_time());
+    result
+}
+
+/// Runs every solution on a pool of `jobs` worker threads and returns the
+/// results sorted by day.
+pub fn run_days(year: u16, solutions: &[&'static dyn Runner], config: &RunConfig, jobs: usize) -> Vec<DayResult> {
+    // Workers can't share the terminal for a fetch prompt
+    let config = match config.fetch {
+        FetchPolicy::Ask if jobs > 1 => RunConfig { fetch: FetchPolicy::Never, ..config.clone() },
+        _ => config.clone(),
+    };
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(solutions.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, solutions.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_day(year, *solution, &config);
+                    results.lock().unwrap_or_else(|e| e.into_inner()).push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    results.sort_by_key(|result| result.day);
+    results
+}
+
+/// [`solve`], turning a panic into [`DayError::Panic`].
+fn solve_isolated(
+    year: u16,
+    solution: &dyn Runner,
+    input: &str,
+    part: Option<u8>,
+    check: bool,
+) -> Result<(Duration, Vec<PartResult>), DayError> {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(year, solution, input, part, check)))
+        .unwrap_or_else(|payload| Err(DayError::Panic(panic_message(payload.as_ref()))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn solve(
+    year: u16,
+    solution: &dyn Runner,
+    input: &str,
+    part: Option<u8>,
+    check: bool,
+) -> Result<(Duration, Vec<PartResult>), DayError> {
+    let day = solution.day();
+    let expected = if check { Some(answers::load(year, day).map_err(DayError::Failed)?) } else { None };
+
+    let started = Instant::now();
+    let parsed = solution.parse(input).map_err(|e| DayError::Failed(format!("Day {}: {}", day, e)))?;
+    let parse_time = started.elapsed();
+
+    let parts = selected_parts(part)
+        .into_iter()
+        .map(|part| {
+            let started = Instant::now();
+            let answer = solution.part(parsed.as_ref(), part);
+            let time = started.elapsed();
+            let check = expected.as_ref().map(|expected| answers::check(&answer, expected.part(part)));
+            PartResult { part, answer, time, check }
+        })
+        .collect();
+
+    Ok((parse_time, parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::solutions;
+    use std::path::PathBuf;
+
+    fn example(day: u8, part: Option<u8>) -> RunConfig {
+        let path = format!("{}/examples/2024/day{:02}/example.txt", env!("CARGO_MANIFEST_DIR"), day);
+        RunConfig { input: InputSource::File(PathBuf::from(path)), fetch: FetchPolicy::Never, part, ..RunConfig::default() }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let result = run_day(2024, solutions::y2024::CALENDAR.get(1).unwrap(), &example(1, None));
+        let parts = result.outcome.as_ref().unwrap();
+        assert_eq!(parts.iter().map(|p| p.part).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(parts[0].answer, 11);
+        assert_eq!(parts[1].answer, 31);
+        assert!(parts.iter().all(|p| p.check.is_none()));
+        assert!(!result.is_failure());
+    }
+
+    #[test]
+    fn test_run_day_single_part_and_errors() {
+        let result = run_day(2024, solutions::y2024::CALENDAR.get(5).unwrap(), &example(5, Some(2)));
+        let parts = result.outcome.unwrap();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].answer, 123);
+
+        let missing = RunConfig { input: InputSource::File(PathBuf::from("does/not/exist.txt")), ..example(5, None) };
+        let result = run_day(2024, solutions::y2024::CALENDAR.get(5).unwrap(), &missing);
+        assert!(result.outcome.is_err());
+        assert!(result.is_failure());
+    }
+
+    #[test]
+    fn test_run_days_sorts_by_day() {
+        // Every day reads day 1's example, so only day 1 succeeds
+        let calendar = &solutions::y2024::CALENDAR;
+        let mut days = calendar.all().to_vec();
+        days.reverse();
+        let results = run_days(2024, &days, &example(1, None), 4);
+        let expected: Vec<u8> = calendar.all().iter().map(|s| s.day()).collect();
+        assert_eq!(results.iter().map(|r| r.day).collect::<Vec<_>>(), expected);
+        assert_eq!(results[0].outcome.as_ref().unwrap()[1].answer, 31);
+        assert!(run_days(2024, &[], &example(1, None), 4).is_empty());
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Parsed = ();
+
+        fn day(&self) -> u8 {
+            24
+        }
+
+        fn name(&self) -> &'static str {
+            "Panics"
+        }
+
+        fn parse(&self, input: &str) -> crate::error::Result<()> {
+            panic!("bad input of {} lines", input.lines().count());
+        }
+
+        fn part1(&self, _: &()) -> Answer {
+            0.into()
+        }
+
+        fn part2(&self, _: &()) -> Answer {
+            0.into()
+        }
+    }
+
+    struct Spins;
+
+    impl Solution for Spins {
+        type Parsed = ();
+
+        fn day(&self) -> u8 {
+            25
+        }
+
+        fn name(&self) -> &'static str {
+            "Spins"
+        }
+
+        fn parse(&self, _: &str) -> crate::error::Result<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _: &()) -> Answer {
+            loop {
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        fn part2(&self, _: &()) -> Answer {
+            0.into()
+        }
+    }
+
+    #[test]
+    fn test_panics_and_timeouts_are_isolated() {
+        let config = RunConfig { timeout: Some(Duration::from_millis(200)), ..example(1, None) };
+        let days: [&'static dyn Runner; 3] = [&Panics, solutions::y2024::CALENDAR.get(1).unwrap(), &Spins];
+        let results = run_days(2024, &days, &config, 3);
+
+        assert_eq!(results[0].outcome.as_ref().unwrap()[0].answer, 11);
+        assert_eq!(results[1].outcome.as_ref().unwrap_err(), &DayError::Panic("bad input of 6 lines".to_string()));
+        assert_eq!(results[2].outcome.as_ref().unwrap_err(), &DayError::Timeout(Duration::from_millis(200)));
+        assert_eq!(results[2].outcome.as_ref().unwrap_err().to_string(), "TIMEOUT after 200.00ms");
+
+        // Without a timeout panics are still caught, on the calling thread
+        let result = run_day(2024, &Panics, &example(1, None));
+        assert!(matches!(result.outcome, Err(DayError::Panic(_))));
+    }
+}
@@ -0,0 +1,267 @@
+// src/scaffold.rs
+//! `new DAY`: generates a solution module from a template, registers it in
+//! `src/solutions/yYYYY/mod.rs` (adding the year if needed) and creates empty
+//! input and example files to fill in.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::examples::{day_examples_dir, EXAMPLES_DIR};
+use crate::input::{day_input_path, input_dir};
+
+const SOLUTIONS_DIR: &str = "src/solutions";
+
+const TEMPLATE: &str = r#"// src/solutions/y{YYYY}/d{DD}.rs
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::log::debug;
+use crate::solution::Solution;
+
+pub struct Day{DD};
+
+impl Solution for Day{DD} {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u8 {
+        {D}
+    }
+
+    fn name(&self) -> &'static str {
+        "{NAME}"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let lines: Vec<String> = input.lines().map(str::to_string).collect();
+        debug!("Read {} lines", lines.len());
+        Ok(lines)
+    }
+
+    fn part1(&self, _lines: &Self::Parsed) -> Answer {
+        "not solved yet".into()
+    }
+
+    fn part2(&self, _lines: &Self::Parsed) -> Answer {
+        "not solved yet".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("../../../examples/{YYYY}/day{DD}/example.txt");
+        let (part1, part2) = Day{DD}.solve_both(input).unwrap();
+        // Replace with the answers given in the puzzle text:
+        // assert_eq!(part1, ...);
+        // assert_eq!(part2, ...);
+        panic!(
+            "fill in examples/{YYYY}/day{DD}/example.answers and the expected answers in this test (got {} and {})",
+            part1, part2
+        );
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"// src/solutions/y{YYYY}/mod.rs
+use super::Calendar;
+
+pub mod d{DD};
+// Future days will be added here
+
+/// Every implemented {YYYY} day, in calendar order.
+pub static CALENDAR: Calendar = Calendar::new({YYYY}, &[
+    &d{DD}::Day{DD},
+]);
+"#;
+
+const ANSWERS_TEMPLATE: &str = "part1:\npart2:\n";
+
+/// Creates everything a new day needs under `root` (the crate directory) and
+/// returns the paths it wrote. Fails without touching anything if the day
+/// already has a module or a registry entry.
+pub fn new_day(root: &Path, year: u16, day: u8, name: Option<&str>) -> Result<Vec<PathBuf>, String> {
+    let solutions_dir = root.join(SOLUTIONS_DIR);
+    let year_dir = solutions_dir.join(format!("y{}", year));
+    let module_path = year_dir.join(format!("d{:02}.rs", day));
+    if module_path.exists() {
+        return Err(format!("{} day {} already exists at {}", year, day, module_path.display()));
+    }
+
+    // Either add the day to an existing year, or create the year and add that
+    // to the list of years
+    let year_path = year_dir.join("mod.rs");
+    let registries = if year_path.exists() {
+        vec![(year_path.clone(), update(&year_path, |source| register_day(source, day))?)]
+    } else {
+        let years_path = solutions_dir.join("mod.rs");
+        vec![
+            (year_path, fill(YEAR_TEMPLATE, year, day)),
+            (years_path.clone(), update(&years_path, |source| register_year(source, year))?),
+        ]
+    };
+
+    let name = name.map_or_else(|| format!("Day {}", day), str::to_string);
+    let module = fill(TEMPLATE, year, day).replace("{NAME}", &name.replace('\\', "\\\\").replace('"', "\\\""));
+
+    let examples_dir = day_examples_dir(&root.join(EXAMPLES_DIR), year, day);
+    let placeholders = [
+        (day_input_path(&root.join(input_dir()), year, day), ""),
+        (examples_dir.join("example.txt"), ""),
+        (examples_dir.join("example.answers"), ANSWERS_TEMPLATE),
+    ];
+
+    let mut written = vec![module_path.clone()];
+    write(&module_path, &module)?;
+    for (path, source) in registries {
+        write(&path, &source)?;
+        written.push(path);
+    }
+    for (path, contents) in placeholders {
+        // Never clobber an input or example that's already been filled in
+        if !path.exists() {
+            write(&path, contents)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{YYYY}", &year.to_string())
+        .replace("{DD}", &format!("{:02}", day))
+        .replace("{D}", &day.to_string())
+}
+
+/// Reads a registry source file and returns it with `edit` applied.
+fn update(path: &Path, edit: impl Fn(&str) -> Result<String, String>) -> Result<String, String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("Error reading {}: {}", path.display(), e))?;
+    edit(&source).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Error creating {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("Error writing {}: {}", path.display(), e))
+}
+
+/// Adds `pub mod dXX;` and the `&dXX::DayXX` entry to a year's module source.
+fn register_day(source: &str, day: u8) -> Result<String, String> {
+    let module = format!("d{:02}", day);
+    register(source, &module, &format!("    &{}::Day{:02},", module, day))
+        .map_err(|e| e.unwrap_or_else(|| format!("day {} is already registered", day)))
+}
+
+/// Adds `pub mod yYYYY;` and the `&yYYYY::CALENDAR` entry to the list of years.
+fn register_year(source: &str, year: u16) -> Result<String, String> {
+    let module = format!("y{}", year);
+    register(source, &module, &format!("    &{}::CALENDAR,", module))
+        .map_err(|e| e.unwrap_or_else(|| format!("year {} is already registered", year)))
+}
+
+/// Inserts the `pub mod` line and registry entry for `module`, keeping both
+/// lists sorted. Module names are zero-padded, so text order is calendar
+/// order. Fails with `None` if the module is already registered.
+fn register(source: &str, module: &str, entry_line: &str) -> Result<String, Option<String>> {
+    let mod_line = format!("pub mod {};", module);
+    let prefix = &module[..1];
+
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+    if lines.iter().any(|line| line.trim() == mod_line || line.trim() == entry_line.trim()) {
+        return Err(None);
+    }
+
+    let mod_of = |line: &str| Some(line.strip_prefix("pub mod ")?.strip_suffix(';')?.to_string());
+    let mod_index = insertion_index(&lines, module, prefix, mod_of)
+        .ok_or_else(|| format!("no `pub mod {}..;` lines found", prefix))?;
+    lines.insert(mod_index, mod_line);
+
+    let entry_of = |line: &str| Some(line.trim().strip_prefix('&')?.split("::").next()?.to_string());
+    let entry_index =
+        insertion_index(&lines, module, prefix, entry_of).ok_or_else(|| "no registry entries found".to_string())?;
+    lines.insert(entry_index, entry_line.to_string());
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Where a line for `module` belongs among the lines `module_of` recognises
+/// (only modules starting with `prefix` count): before the first later
+/// module, or after the last one.
+fn insertion_index(
+    lines: &[String],
+    module: &str,
+    prefix: &str,
+    module_of: impl Fn(&str) -> Option<String>,
+) -> Option<usize> {
+    let matching: Vec<(usize, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| module_of(line).filter(|m| m.starts_with(prefix)).map(|m| (i, m)))
+        .collect();
+    let (last, _) = matching.last()?;
+    Some(matching.iter().find(|(_, m)| m.as_str() > module).map_or(last + 1, |(i, _)| *i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    const YEARS: &str = "\
+use crate::solution::Runner;
+
+pub mod y2024;
+// Future years will be added here
+
+static CALENDARS: &[&Calendar] = &[
+    &y2024::CALENDAR,
+];
+";
+
+    #[test]
+    fn test_register_keeps_calendar_order() {
+        let source = fill(YEAR_TEMPLATE, 2024, 6);
+        let source = register_day(&source, 7).unwrap();
+        assert!(source.contains("pub mod d06;\npub mod d07;\n// Future days"), "{}", source);
+        assert!(source.contains("    &d06::Day06,\n    &d07::Day07,\n]);"), "{}", source);
+
+        let source = register_day(&source, 3).unwrap();
+        assert!(source.contains("pub mod d03;\npub mod d06;\npub mod d07;"), "{}", source);
+        assert!(source.contains("    &d03::Day03,\n    &d06::Day06,"), "{}", source);
+
+        assert!(register_day(&source, 6).unwrap_err().contains("already registered"));
+        assert!(register_day("fn main() {}\n", 7).is_err());
+
+        let years = register_year(YEARS, 2015).unwrap();
+        assert!(years.contains("pub mod y2015;\npub mod y2024;"), "{}", years);
+        assert!(years.contains("    &y2015::CALENDAR,\n    &y2024::CALENDAR,\n];"), "{}", years);
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = TempDir::new("scaffold");
+        write(&root.join(SOLUTIONS_DIR).join("mod.rs"), YEARS).unwrap();
+        write(&root.join(SOLUTIONS_DIR).join("y2024/mod.rs"), &fill(YEAR_TEMPLATE, 2024, 6)).unwrap();
+
+        let written = new_day(&root, 2024, 7, Some("Bridge Repair")).unwrap();
+        let module = fs::read_to_string(root.join("src/solutions/y2024/d07.rs")).unwrap();
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("\"Bridge Repair\""));
+        assert!(module.contains("include_str!(\"../../../examples/2024/day07/example.txt\")"));
+        assert!(root.join("examples/2024/day07/example.answers").exists());
+        assert_eq!(written.len(), 5);
+
+        let err = new_day(&root, 2024, 7, None).unwrap_err();
+        assert!(err.contains("already exists"), "{}", err);
+        let err = new_day(&root, 2024, 6, None).unwrap_err();
+        assert!(err.contains("already registered"), "{}", err);
+
+        new_day(&root, 2015, 1, None).unwrap();
+        let years = fs::read_to_string(root.join("src/solutions/mod.rs")).unwrap();
+        assert!(years.contains("pub mod y2015;\npub mod y2024;"), "{}", years);
+        assert!(root.join("src/solutions/y2015/d01.rs").exists());
+    }
+}
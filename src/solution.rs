@@ -0,0 +1,80 @@
+// src/solution.rs
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::error::Result;
+
+/// A single day's puzzle, split into a parse step and the two parts.
+///
+/// Implementors are zero-sized markers (`y2024::d01::Day01`, ...) so they can live in
+/// the static registry in `solutions`.
+pub trait Solution {
+    type Parsed;
+
+    fn day(&self) -> u8;
+    fn name(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+    fn part1(&self, parsed: &Self::Parsed) -> Answer;
+    fn part2(&self, parsed: &Self::Parsed) -> Answer;
+
+    /// Parses once and runs both parts; the runner times the steps
+    /// separately, so this is mostly for tests and quick experiments.
+    fn solve_both(&self, input: &str) -> Result<(Answer, Answer)> {
+        let parsed = self.parse(input)?;
+        Ok((self.part1(&parsed), self.part2(&parsed)))
+    }
+}
+
+/// Object-safe view of a `Solution`, so days with different parsed types can
+/// be stored side by side and driven generically by the runner.
+pub trait Runner: Sync {
+    fn day(&self) -> u8;
+    fn name(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn part1(&self, parsed: &dyn Any) -> Answer;
+    fn part2(&self, parsed: &dyn Any) -> Answer;
+
+    /// Runs part 1 or 2 by number.
+    fn part(&self, parsed: &dyn Any, part: u8) -> Answer {
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => panic!("invalid part {}", part),
+        }
+    }
+}
+
+impl<S> Runner for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn name(&self) -> &'static str {
+        Solution::name(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> Answer {
+        Solution::part1(self, downcast::<S>(parsed))
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Answer {
+        Solution::part2(self, downcast::<S>(parsed))
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input passed to a different day's solution")
+}
@@ -1 +1,77 @@
-pub mod s01;
\ No newline at end of file
+// src/solutions/mod.rs
+use crate::solution::Runner;
+
+pub mod y2024;
+// Future years will be added here
+
+/// One event year's implemented days.
+pub struct Calendar {
+    year: u16,
+    days: &'static [&'static dyn Runner],
+}
+
+impl Calendar {
+    pub const fn new(year: u16, days: &'static [&'static dyn Runner]) -> Self {
+        Calendar { year, days }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Every implemented day, in calendar order.
+    pub fn all(&self) -> &'static [&'static dyn Runner] {
+        self.days
+    }
+
+    pub fn get(&self, day: u8) -> Option<&'static dyn Runner> {
+        self.days.iter().copied().find(|solution| solution.day() == day)
+    }
+}
+
+/// Every year with solutions, oldest first.
+static CALENDARS: &[&Calendar] = &[
+    &y2024::CALENDAR,
+];
+
+pub fn calendars() -> &'static [&'static Calendar] {
+    CALENDARS
+}
+
+pub fn calendar(year: u16) -> Option<&'static Calendar> {
+    CALENDARS.iter().copied().find(|calendar| calendar.year() == year)
+}
+
+/// The most recent year, used when `--year` isn't given.
+pub fn latest() -> &'static Calendar {
+    CALENDARS.last().expect("at least one year is registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendars_are_sorted_and_unique() {
+        let years: Vec<u16> = calendars().iter().map(|calendar| calendar.year()).collect();
+        assert!(years.windows(2).all(|w| w[0] < w[1]), "years out of order: {:?}", years);
+
+        for calendar in calendars() {
+            let days: Vec<u8> = calendar.all().iter().map(|solution| solution.day()).collect();
+            assert!(days.windows(2).all(|w| w[0] < w[1]), "{} registry out of order: {:?}", calendar.year(), days);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let calendar = calendar(2024).unwrap();
+        assert_eq!(calendar.get(4).map(|solution| solution.name()), Some("Ceres Search"));
+        for solution in calendar.all() {
+            assert_eq!(calendar.get(solution.day()).map(|found| found.day()), Some(solution.day()));
+        }
+        // Neither can ever be registered
+        assert!(calendar.get(26).is_none());
+        assert!(super::calendar(0).is_none());
+        assert_eq!(latest().year(), calendars().last().unwrap().year());
+    }
+}
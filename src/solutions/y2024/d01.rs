@@ -0,0 +1,109 @@
+// src/solutions/y2024/d01.rs
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::{parse_number, AocError, Result};
+use crate::log::debug;
+use crate::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lists(input)
+    }
+
+    fn part1(&self, (left, right): &Self::Parsed) -> Answer {
+        total_distance(left, right).into()
+    }
+
+    fn part2(&self, (left, right): &Self::Parsed) -> Answer {
+        similarity_score(left, right).into()
+    }
+}
+
+fn parse_lists(input: &str) -> Result<(Vec<i64>, Vec<i64>)> {
+    let mut left_numbers: Vec<i64> = Vec::new();
+    let mut right_numbers: Vec<i64> = Vec::new();
+
+    // Parse input into two vectors
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let numbers = line
+            .split_whitespace()
+            .map(|s| parse_number(i, line, s))
+            .collect::<Result<Vec<i64>>>()?;
+
+        if numbers.len() != 2 {
+            return Err(AocError::line(i, format!("expected 2 numbers, found {}", numbers.len())));
+        }
+        left_numbers.push(numbers[0]);
+        right_numbers.push(numbers[1]);
+    }
+
+    debug!("Parsed {} location ID pairs", left_numbers.len());
+    Ok((left_numbers, right_numbers))
+}
+
+// Part 1: Calculate total distance
+fn total_distance(left_numbers: &[i64], right_numbers: &[i64]) -> i64 {
+    let mut left_sorted = left_numbers.to_vec();
+    let mut right_sorted = right_numbers.to_vec();
+    left_sorted.sort_unstable();
+    right_sorted.sort_unstable();
+
+    left_sorted.iter()
+        .zip(right_sorted.iter())
+        .map(|(left, right)| (left - right).abs())
+        .sum()
+}
+
+// Part 2: Calculate similarity score
+fn similarity_score(left_numbers: &[i64], right_numbers: &[i64]) -> i64 {
+    let mut right_counts: HashMap<i64, i64> = HashMap::new();
+    for num in right_numbers {
+        *right_counts.entry(*num).or_insert(0) += 1;
+    }
+
+    left_numbers.iter()
+        .map(|num| num * right_counts.get(num).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("../../../examples/2024/day01/example.txt");
+        let (distance, similarity) = Day01.solve_both(input).unwrap();
+        assert_eq!(distance, 11);
+        assert_eq!(similarity, 31);
+    }
+
+    #[test]
+    fn test_malformed_lines() {
+        assert_eq!(
+            parse_lists("3   4\n4   x\n").unwrap_err().to_string(),
+            "line 2, column 5: expected a number, found 'x'"
+        );
+        assert_eq!(
+            parse_lists("3   4\n4\n").unwrap_err().to_string(),
+            "line 2, column 1: expected 2 numbers, found 1"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// src/solutions/y2024/d02.rs
+use crate::answer::Answer;
+use crate::error::{parse_number, Result};
+use crate::log::debug;
+use crate::solution::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<i64>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_reports(input)
+    }
+
+    fn part1(&self, reports: &Self::Parsed) -> Answer {
+        count_safe_reports(reports, false).into()
+    }
+
+    fn part2(&self, reports: &Self::Parsed) -> Answer {
+        count_safe_reports(reports, true).into()
+    }
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<i64>>> {
+    let reports: Vec<Vec<i64>> = input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            // Convert string numbers to integers
+            line.split_whitespace()
+                .map(|x| parse_number(i, line, x))
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+
+    debug!("Parsed {} reports", reports.len());
+    Ok(reports)
+}
+
+fn is_safe_sequence(nums: &[i64]) -> bool {
+    if nums.len() <= 1 {
+        return true;
+    }
+
+    let differences: Vec<i64> = nums.windows(2)
+        .map(|w| w[1] - w[0])
+        .collect();
+
+    let should_increase = differences[0] > 0;
+
+    for &diff in &differences {
+        // Check if direction matches what it should be
+        if (should_increase && diff <= 0) || (!should_increase && diff >= 0) {
+            return false;
+        }
+
+        // Check if difference is between 1 and 3 (absolute value)
+        if diff.abs() < 1 || diff.abs() > 3 {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_safe_with_dampener(nums: &[i64], use_dampener: bool) -> bool {
+    // First check if safe without removing any level
+    if is_safe_sequence(nums) {
+        return true;
+    }
+
+    // If not using dampener and we got here, it's not safe
+    if !use_dampener {
+        return false;
+    }
+
+    // Try removing each level one at a time
+    for i in 0..nums.len() {
+        let mut dampened_nums = nums.to_vec();
+        dampened_nums.remove(i);
+        if is_safe_sequence(&dampened_nums) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn count_safe_reports(reports: &[Vec<i64>], use_dampener: bool) -> i64 {
+    reports.iter()
+        .filter(|nums| is_safe_with_dampener(nums, use_dampener))
+        .count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let test_data = include_str!("../../../examples/2024/day02/example.txt");
+        let (part1, part2) = Day02.solve_both(test_data).unwrap();
+        assert_eq!(part1, 2);
+        assert_eq!(part2, 4);
+    }
+
+    #[test]
+    fn test_malformed_level() {
+        assert_eq!(
+            parse_reports("7 6 4\n1 2 -\n").unwrap_err().to_string(),
+            "line 2, column 5: expected a number, found '-'"
+        );
+    }
+}
@@ -1,4 +1,8 @@
-// src/solutions/s03.rs
+// src/solutions/y2024/d03.rs
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::log::{debug, trace};
+use crate::solution::Solution;
 
 #[derive(Debug)]
 struct Multiplication {
@@ -12,14 +16,32 @@ impl Multiplication {
     }
 }
 
-pub fn solve(input: &str) -> i64 {
-    solve_both(input).1
-}
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = String;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn name(&self) -> &'static str {
+        "Mull It Over"
+    }
 
-pub fn solve_both(input: &str) -> (i64, i64) {
-    let part1 = process_memory(input, false);
-    let part2 = process_memory(input, true);
-    (part1, part2)
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        // Corrupted memory is the puzzle, so any text is valid input
+        debug!("Read {} bytes of memory", input.len());
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, memory: &Self::Parsed) -> Answer {
+        process_memory(memory, false).into()
+    }
+
+    fn part2(&self, memory: &Self::Parsed) -> Answer {
+        process_memory(memory, true).into()
+    }
 }
 
 fn process_memory(input: &str, handle_conditionals: bool) -> i64 {
@@ -39,6 +61,8 @@ fn process_memory(input: &str, handle_conditionals: bool) -> i64 {
         if let Some((mult, new_i)) = parse_multiplication(&chars[i..]) {
             if !handle_conditionals || enabled {
                 total += mult.result();
+            } else {
+                trace!("Skipping disabled {:?}", mult);
             }
             i += new_i;
         } else {
@@ -74,7 +98,6 @@ fn parse_multiplication(chars: &[char]) -> Option<(Multiplication, usize)> {
     let mut i = 4; // Skip "mul("
     let mut num_str = String::new();
     let mut x = None;
-    let mut y = None;
 
     while i < chars.len() {
         match chars[i] {
@@ -88,14 +111,11 @@ fn parse_multiplication(chars: &[char]) -> Option<(Multiplication, usize)> {
                 }
             }
             ')' => {
-                if let Ok(num) = num_str.parse() {
-                    y = Some(num);
-                    if let (Some(x_val), Some(y_val)) = (x, y) {
-                        return Some((
-                            Multiplication { x: x_val, y: y_val },
-                            i + 1
-                        ));
-                    }
+                if let (Some(x_val), Ok(y_val)) = (x, num_str.parse()) {
+                    return Some((
+                        Multiplication { x: x_val, y: y_val },
+                        i + 1
+                    ));
                 }
                 return None;
             }
@@ -113,13 +133,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let input = include_str!("../../../examples/2024/day03/part1.txt");
         assert_eq!(process_memory(input, false), 161);
     }
 
     #[test]
     fn test_part2() {
-        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)do()?mul(8,5))";
+        let input = include_str!("../../../examples/2024/day03/part2.txt");
         assert_eq!(process_memory(input, true), 48);
     }
 }
\ No newline at end of file
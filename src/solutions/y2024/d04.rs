@@ -0,0 +1,104 @@
+// src/solutions/y2024/d04.rs
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::geometry::{Direction8, Point};
+use crate::grid::Grid;
+use crate::log::debug;
+use crate::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed) -> Answer {
+        count_xmas(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> Answer {
+        count_x_mas(grid).into()
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::parse(input)?;
+    debug!("Word search is {}x{}", grid.width(), grid.height());
+    Ok(grid)
+}
+
+// Part 1
+fn count_xmas(grid: &Grid<char>) -> i64 {
+    let target = ['X', 'M', 'A', 'S'];
+    let mut count = 0;
+
+    for (start, _) in grid.iter().filter(|(_, &ch)| ch == 'X') {
+        for direction in Direction8::ALL {
+            if grid.ray(start, direction.delta()).take(4).copied().eq(target) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn check_mas(grid: &Grid<char>, center: Point) -> bool {
+    let cell = |direction: Direction8| grid.get(center + direction.delta()).copied();
+    let middle = grid.get(center).copied();
+
+    // Get characters for both diagonals; off-grid cells never match
+    let mas1 = [cell(Direction8::UpLeft), middle, cell(Direction8::DownRight)];
+    let mas2 = [cell(Direction8::UpRight), middle, cell(Direction8::DownLeft)];
+
+    // Check if either diagonal can form MAS (forward or backward)
+    let is_mas = |diagonal: [Option<char>; 3]| {
+        diagonal == [Some('M'), Some('A'), Some('S')] || diagonal == [Some('S'), Some('A'), Some('M')]
+    };
+
+    is_mas(mas1) && is_mas(mas2)
+}
+
+fn count_x_mas(grid: &Grid<char>) -> i64 {
+    // Check each position that could be the center of an X
+    grid.iter()
+        .filter(|&(center, &ch)| ch == 'A' && check_mas(grid, center))
+        .count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xmas() {
+        let input = include_str!("../../../examples/2024/day04/xmas.txt");
+        assert_eq!(count_xmas(&parse_grid(input).unwrap()), 18);
+    }
+
+    #[test]
+    fn test_x_mas() {
+        let input = include_str!("../../../examples/2024/day04/x_mas.txt");
+        assert_eq!(count_x_mas(&parse_grid(input).unwrap()), 9);
+    }
+
+    #[test]
+    fn test_ragged_grid() {
+        assert_eq!(
+            parse_grid("XMAS\nXMA\n").unwrap_err().to_string(),
+            "line 2, column 1: expected 4 cells, found 3"
+        );
+        assert!(parse_grid("\n").is_err());
+    }
+}
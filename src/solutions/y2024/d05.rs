@@ -0,0 +1,195 @@
+use std::collections::{HashMap, BinaryHeap};
+
+use crate::answer::Answer;
+use crate::error::{parse_number, AocError, Result};
+use crate::log::{debug, warning};
+use crate::solution::Solution;
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct PrintQueue {
+    rules: HashMap<u32, Vec<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day05 {
+    type Parsed = PrintQueue;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_queue(input)
+    }
+
+    fn part1(&self, queue: &Self::Parsed) -> Answer {
+        sum_ordered_middles(queue).into()
+    }
+
+    fn part2(&self, queue: &Self::Parsed) -> Answer {
+        sum_reordered_middles(queue).into()
+    }
+}
+
+fn parse_queue(input: &str) -> Result<PrintQueue> {
+    let mut rules: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut updates: Vec<Vec<u32>> = Vec::new();
+    let mut in_updates = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+
+        // A blank line separates the rules section from the updates section
+        if line.is_empty() {
+            in_updates |= !rules.is_empty();
+            continue;
+        }
+
+        if in_updates {
+            let update = line.split(',')
+                .map(|page| parse_number(i, line, page.trim()))
+                .collect::<Result<Vec<u32>>>()?;
+            updates.push(update);
+        } else {
+            let (x, y) = line.split_once('|')
+                .ok_or_else(|| AocError::line(i, "expected a rule of the form X|Y"))?;
+            let x: u32 = parse_number(i, line, x.trim())?;
+            let y: u32 = parse_number(i, line, y.trim())?;
+            rules.entry(x).or_default().push(y);
+        }
+    }
+
+    if updates.is_empty() {
+        return Err(AocError::input("expected ordering rules, a blank line, then updates"));
+    }
+
+    debug!("Parsed rules for {} pages and {} updates", rules.len(), updates.len());
+    Ok(PrintQueue { rules, updates })
+}
+
+// Updates we can take a middle page from; even-length updates are skipped
+fn odd_updates(queue: &PrintQueue) -> impl Iterator<Item = &Vec<u32>> {
+    queue.updates.iter().filter(|update| update.len() % 2 == 1)
+}
+
+// Part 1: correctly ordered updates contribute their middle page
+fn sum_ordered_middles(queue: &PrintQueue) -> u32 {
+    odd_updates(queue)
+        .filter(|update| is_correctly_ordered(update, &queue.rules))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+// Part 2: the rest are reordered using topological sort first
+fn sum_reordered_middles(queue: &PrintQueue) -> u32 {
+    odd_updates(queue)
+        .filter(|update| !is_correctly_ordered(update, &queue.rules))
+        .filter_map(|update| match topological_sort(update, &queue.rules) {
+            Ok(sorted_update) => Some(sorted_update),
+            Err(e) => {
+                // Cycle detected, skip this update
+                warning!("Skipping update {:?}: {}", update, e);
+                None
+            }
+        })
+        .map(|sorted_update| sorted_update[sorted_update.len() / 2])
+        .sum()
+}
+
+fn is_correctly_ordered(update: &[u32], rules: &HashMap<u32, Vec<u32>>) -> bool {
+    // Create position map
+    let position_map: HashMap<u32, usize> = update.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+    // Check all relevant rules
+    for &page in update {
+        if let Some(after_pages) = rules.get(&page) {
+            for &after_page in after_pages {
+                if position_map.contains_key(&after_page)
+                    && position_map[&after_page] <= position_map[&page]
+                {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn topological_sort(pages: &[u32], rules: &HashMap<u32, Vec<u32>>) -> Result<Vec<u32>> {
+    // Build adjacency list and in-degree map
+    let mut adj_list: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut in_degree: HashMap<u32, usize> = HashMap::new();
+
+    for &page in pages {
+        adj_list.insert(page, Vec::new());
+        in_degree.insert(page, 0);
+    }
+
+    for &page in pages {
+        if let Some(dependents) = rules.get(&page) {
+            for &dep in dependents {
+                if pages.contains(&dep) {
+                    adj_list.get_mut(&page).unwrap().push(dep);
+                    *in_degree.get_mut(&dep).unwrap() += 1;
+                }
+            }
+        }
+    }
+
+    // Initialize heap with pages having zero in-degree
+    let mut heap: BinaryHeap<u32> = pages.iter()
+        .filter(|&&p| in_degree[&p] == 0)
+        .cloned()
+        .collect();
+
+    let mut sorted: Vec<u32> = Vec::new();
+
+    while let Some(node) = heap.pop() {
+        sorted.push(node);
+        for &neighbor in adj_list.get(&node).unwrap() {
+            *in_degree.get_mut(&neighbor).unwrap() -= 1;
+            if in_degree[&neighbor] == 0 {
+                heap.push(neighbor);
+            }
+        }
+    }
+
+    // Check for cycles
+    if sorted.len() != pages.len() {
+        Err(AocError::input("cycle detected in page ordering rules"))
+    } else {
+        Ok(sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("../../../examples/2024/day05/example.txt");
+        let (part1, part2) = Day05.solve_both(input).unwrap();
+        assert_eq!(part1, 143);
+        assert_eq!(part2, 123);
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            parse_queue("47|53\n97-13\n\n75,47,61\n").unwrap_err().to_string(),
+            "line 2, column 1: expected a rule of the form X|Y"
+        );
+        assert_eq!(
+            parse_queue("47|53\n\n75,47,6x\n").unwrap_err().to_string(),
+            "line 3, column 7: expected a number, found '6x'"
+        );
+        assert!(parse_queue("47|53\n97|13\n").is_err());
+    }
+}
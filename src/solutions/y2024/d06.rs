@@ -0,0 +1,429 @@
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::geometry::{Direction4, Point};
+use crate::grid::Grid;
+use crate::log::{debug, Progress};
+use crate::solution::Solution;
+
+pub mod render;
+pub mod sim;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    pos: Point,
+    dir: Direction4,
+}
+
+pub struct Map {
+    grid: Grid<char>,
+}
+
+/// Where a patrol went, and whether it ended by walking off the map or by
+/// getting back to a state it had already been in.
+struct Walk {
+    /// The directions the guard faced on each cell, one bit per [`Direction4`].
+    seen: Grid<u8>,
+    loops: bool,
+}
+
+impl Walk {
+    fn visited(&self) -> usize {
+        self.seen.iter().filter(|(_, &dirs)| dirs != 0).count()
+    }
+}
+
+impl Map {
+    fn new(input: &str) -> Result<(Self, Point, Direction4)> {
+        let mut guards = 0;
+        let grid = Grid::parse_with(input, |ch| match ch {
+            '.' | '#' => Ok(ch),
+            '^' => {
+                guards += 1;
+                if guards > 1 {
+                    Err("found a second guard".to_string())
+                } else {
+                    Ok(ch)
+                }
+            }
+            _ => Err(format!("unexpected '{}' in map", ch)),
+        })?;
+
+        let start_pos = grid.find(&'^').ok_or_else(|| AocError::input("no guard ('^') on the map"))?;
+
+        debug!("Map dimensions: {}x{}", grid.width(), grid.height());
+        debug!("Found start position at: {}", start_pos);
+
+        Ok((Map { grid }, start_pos, Direction4::Up))
+    }
+
+    fn is_within_bounds(&self, pos: &Point) -> bool {
+        self.grid.in_bounds(*pos)
+    }
+
+    fn is_obstacle(&self, pos: &Point, extra_obstacle: Option<Point>) -> bool {
+        if !self.is_within_bounds(pos) {
+            return true;
+        }
+        if let Some(obstacle) = extra_obstacle {
+            if *pos == obstacle {
+                return true;
+            }
+        }
+        self.grid[*pos] == '#'
+    }
+
+    /// The guard's state after every move or turn from `start`, until they
+    /// walk off the map. Never ends if they're caught in a loop.
+    fn steps(&self, start: State, extra_obstacle: Option<Point>) -> impl Iterator<Item = State> + '_ {
+        std::iter::successors(Some(start), move |state| {
+            let next_pos = state.pos + state.dir.delta();
+
+            if !self.is_within_bounds(&next_pos) {
+                None
+            } else if self.is_obstacle(&next_pos, extra_obstacle) {
+                Some(State { pos: state.pos, dir: state.dir.turn_right() })
+            } else {
+                Some(State { pos: next_pos, dir: state.dir })
+            }
+        })
+    }
+
+    fn simulate_path(&self, start: State, extra_obstacle: Option<Point>) -> Walk {
+        let mut seen = Grid::new(self.grid.width(), self.grid.height(), 0u8);
+
+        for state in self.steps(start, extra_obstacle) {
+            // The next move depends on nothing but the current state, so
+            // reaching one a second time means the guard repeats forever
+            let dir_bit = 1 << state.dir as u8;
+            if seen[state.pos] & dir_bit != 0 {
+                return Walk { seen, loops: true };
+            }
+            seen[state.pos] |= dir_bit;
+        }
+
+        Walk { seen, loops: false }
+    }
+}
+
+/// For every cell and direction, the cell where a guard walking that way
+/// stops because the next one is an obstacle, or `None` if they walk off the
+/// map. Lets a patrol move a whole straight run at a time.
+struct JumpTable {
+    stops: Grid<[Option<Point>; 4]>,
+}
+
+impl JumpTable {
+    fn new(map: &Map) -> Self {
+        let grid = &map.grid;
+        let mut stops = Grid::new(grid.width(), grid.height(), [None; 4]);
+
+        for dir in Direction4::ALL {
+            // Walk each row or column backwards from the edge the guard would
+            // leave by, remembering the cell just past the last obstacle
+            let back = -dir.delta();
+            let edge = grid.iter().map(|(pos, _)| pos).filter(|&pos| !grid.in_bounds(pos + dir.delta()));
+            for mut pos in edge {
+                let mut stop = None;
+                while grid.in_bounds(pos) {
+                    if grid[pos] == '#' {
+                        stop = Some(pos + back);
+                    } else {
+                        stops[pos][dir as usize] = stop;
+                    }
+                    pos += back;
+                }
+            }
+        }
+
+        JumpTable { stops }
+    }
+
+    /// Where the guard in `state` stops, taking `extra_obstacle` into account.
+    fn jump(&self, state: State, extra_obstacle: Option<Point>) -> Option<Point> {
+        let stop = self.stops[state.pos][state.dir as usize];
+        let Some(obstacle) = extra_obstacle else {
+            return stop;
+        };
+
+        // How far ahead the extra obstacle and the stop are, if at all
+        let delta = state.dir.delta();
+        let offset = obstacle - state.pos;
+        let ahead = offset.row * delta.row + offset.col * delta.col;
+        let in_line = offset.row * delta.col == offset.col * delta.row;
+        let blocks = in_line && ahead > 0 && stop.is_none_or(|stop| {
+            let to_stop = stop - state.pos;
+            ahead <= to_stop.row * delta.row + to_stop.col * delta.col
+        });
+
+        if blocks {
+            Some(obstacle - delta)
+        } else {
+            stop
+        }
+    }
+
+    /// Follows the guard from `start` with an extra obstacle, one straight
+    /// run at a time, and reports whether they end up going round in circles.
+    ///
+    /// Only the states right after each turn are recorded, in `turns`, which
+    /// is shared between calls: a state counts as seen if it holds `stamp`,
+    /// so every call must use a new one and nothing needs clearing.
+    fn loops(&self, start: State, extra_obstacle: Point, turns: &mut Grid<[u32; 4]>, stamp: u32) -> bool {
+        let mut state = start;
+        while let Some(stop) = self.jump(state, Some(extra_obstacle)) {
+            state = State { pos: stop, dir: state.dir.turn_right() };
+            let seen = &mut turns[stop][state.dir as usize];
+            if *seen == stamp {
+                return true;
+            }
+            *seen = stamp;
+        }
+        false
+    }
+}
+
+pub struct Day06;
+
+pub struct Patrol {
+    map: Map,
+    start_pos: Point,
+    start_dir: Direction4,
+}
+
+impl Patrol {
+    fn start(&self) -> State {
+        State { pos: self.start_pos, dir: self.start_dir }
+    }
+}
+
+impl Solution for Day06 {
+    type Parsed = Patrol;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let (map, start_pos, start_dir) = Map::new(input)?;
+        Ok(Patrol { map, start_pos, start_dir })
+    }
+
+    fn part1(&self, patrol: &Self::Parsed) -> Answer {
+        solve_part1(&patrol.map, patrol.start_pos, patrol.start_dir).into()
+    }
+
+    fn part2(&self, patrol: &Self::Parsed) -> Answer {
+        let positions = loop_obstacles(&patrol.map, patrol.start_pos, patrol.start_dir);
+        debug!("Part 2 finished, found {} loop-creating positions", positions.len());
+        positions.len().into()
+    }
+}
+
+fn solve_part1(map: &Map, start_pos: Point, start_dir: Direction4) -> usize {
+    let walk = map.simulate_path(State { pos: start_pos, dir: start_dir }, None);
+    if walk.loops {
+        debug!("The guard never leaves the map");
+    }
+
+    debug!("Part 1 finished, visited {} positions", walk.visited());
+    walk.visited()
+}
+
+/// Every position where one more obstacle traps the guard in a loop, in
+/// route order.
+///
+/// An obstacle can only change the patrol if it's on the original route, so
+/// this walks that route and tries each cell on it the first time the guard
+/// is about to step there. That way the guard's state just before the cell is
+/// already known, and only the rest of the patrol needs simulating.
+fn loop_obstacles(map: &Map, start_pos: Point, start_dir: Direction4) -> Vec<Point> {
+    if map.simulate_path(State { pos: start_pos, dir: start_dir }, None).loops {
+        // Then an obstacle anywhere off the route keeps the loop too
+        debug!("The guard never leaves the map, trying every position");
+        return brute_force_loop_obstacles(map, start_pos, start_dir);
+    }
+
+    let jumps = JumpTable::new(map);
+    let mut turns = Grid::new(map.grid.width(), map.grid.height(), [0u32; 4]);
+    let mut tried = Grid::new(map.grid.width(), map.grid.height(), false);
+    tried[start_pos] = true;
+
+    let mut state = State { pos: start_pos, dir: start_dir };
+    let mut candidates = 0;
+    let mut loop_creating_positions = Vec::new();
+    loop {
+        let next_pos = state.pos + state.dir.delta();
+        if !map.is_within_bounds(&next_pos) {
+            break;
+        }
+        if map.is_obstacle(&next_pos, None) {
+            state.dir = state.dir.turn_right();
+            continue;
+        }
+
+        // Any later visit to this cell would have hit the obstacle here first
+        if !tried[next_pos] {
+            tried[next_pos] = true;
+            candidates += 1;
+            if jumps.loops(state, next_pos, &mut turns, candidates) {
+                loop_creating_positions.push(next_pos);
+            }
+        }
+        state.pos = next_pos;
+    }
+
+    debug!("Tried {} positions on the route", candidates);
+    loop_creating_positions
+}
+
+/// Tries an obstacle on every free cell and simulates the whole patrol.
+fn brute_force_loop_obstacles(map: &Map, start_pos: Point, start_dir: Direction4) -> Vec<Point> {
+    let mut progress = Progress::new("Checking obstacle positions", map.grid.width() * map.grid.height());
+    let positions = map
+        .grid
+        .iter()
+        .inspect(|_| progress.tick())
+        .filter(|&(pos, &cell)| pos != start_pos && cell == '.')
+        .filter(|&(pos, _)| map.simulate_path(State { pos: start_pos, dir: start_dir }, Some(pos)).loops)
+        .map(|(pos, _)| pos)
+        .collect();
+    progress.finish();
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("../../../examples/2024/day06/example.txt");
+        let (part1, part2) = Day06.solve_both(input).unwrap();
+        assert_eq!(part1, 41);
+        assert_eq!(part2, 6);
+    }
+
+    /// A corridor `length` cells tall with the guard at the bottom, walking
+    /// straight up and off the map.
+    fn corridor(length: usize) -> String {
+        ".\n".repeat(length - 1) + "^\n"
+    }
+
+    /// Four rows with obstacles placed so the guard circles a 2 x (`width` - 2)
+    /// rectangle forever, taking about twice `width` steps per lap.
+    fn racetrack(width: usize) -> String {
+        let mut rows = vec![vec!['.'; width]; 4];
+        rows[0][1] = '#';
+        rows[1][width - 1] = '#';
+        rows[2][0] = '#';
+        rows[2][1] = '^';
+        rows[3][width - 2] = '#';
+        rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+    }
+
+    #[test]
+    fn test_long_patrols() {
+        // Both take more than 10,000 steps
+        let patrol = Day06.parse(&corridor(12_001)).unwrap();
+        assert_eq!(Day06.part1(&patrol), 12_001);
+        assert!(!patrol.map.simulate_path(State { pos: patrol.start_pos, dir: patrol.start_dir }, None).loops);
+
+        let patrol = Day06.parse(&racetrack(6_000)).unwrap();
+        let walk = patrol.map.simulate_path(State { pos: patrol.start_pos, dir: patrol.start_dir }, None);
+        assert!(walk.loops);
+        assert_eq!(walk.visited(), 2 * 5_998);
+    }
+
+    #[test]
+    fn test_long_patrol_part2() {
+        // Without the obstacle on the left the guard leaves the racetrack;
+        // putting it back is the only way to trap them
+        let open = racetrack(6_000).replacen("#^", ".^", 1);
+        assert_eq!(Day06.part2(&Day06.parse(&open).unwrap()), 1);
+    }
+
+    /// Small maps with random walls and a guard facing up somewhere.
+    fn random_maps(count: usize) -> Vec<String> {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        (0..count)
+            .map(|_| {
+                let (width, height) = (3 + random() as usize % 14, 3 + random() as usize % 14);
+                let mut cells: Vec<char> =
+                    (0..width * height).map(|_| if random() % 6 == 0 { '#' } else { '.' }).collect();
+                cells[random() as usize % (width * height)] = '^';
+                cells.chunks(width).map(|row| row.iter().collect::<String>() + "\n").collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_part2_matches_brute_force() {
+        for input in random_maps(200) {
+            let patrol = Day06.parse(&input).unwrap();
+            let mut fast = loop_obstacles(&patrol.map, patrol.start_pos, patrol.start_dir);
+            fast.sort();
+            assert_eq!(
+                fast,
+                brute_force_loop_obstacles(&patrol.map, patrol.start_pos, patrol.start_dir),
+                "\n{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulation_matches_simulate_path() {
+        // `sim::Simulation` moves its guards with its own copy of the rules;
+        // with the defaults it must walk exactly the same route
+        for input in random_maps(200) {
+            let patrol = Day06.parse(&input).unwrap();
+            let walk = patrol.map.simulate_path(patrol.start(), None);
+            let states: usize = walk.seen.iter().map(|(_, dirs)| dirs.count_ones() as usize).sum();
+
+            let mut sim = sim::Simulation::parse(&input).unwrap();
+            match sim.run() {
+                sim::Outcome::Cleared { ticks } => {
+                    assert!(!walk.loops, "\n{}", input);
+                    assert_eq!(ticks, states, "\n{}", input);
+                    sim.by_ref().for_each(drop);
+                }
+                sim::Outcome::Loops { start, length } => {
+                    assert!(walk.loops, "\n{}", input);
+                    assert_eq!(start + length, states, "\n{}", input);
+                    for _ in 0..states {
+                        sim.step();
+                    }
+                }
+            }
+            assert_eq!(sim.visited(), walk.visited(), "\n{}", input);
+        }
+    }
+
+    #[test]
+    fn test_boxed_in_guard_loops() {
+        let patrol = Day06.parse(".#.\n#^#\n.#.\n").unwrap();
+        let walk = patrol.map.simulate_path(State { pos: patrol.start_pos, dir: patrol.start_dir }, None);
+        assert!(walk.loops);
+        assert_eq!(walk.visited(), 1);
+    }
+
+    #[test]
+    fn test_malformed_map() {
+        let err = |input: &str| Map::new(input).err().map(|e| e.to_string());
+        assert_eq!(err("..#\n.^.\n.."), Some("line 3, column 1: expected 3 cells, found 2".to_string()));
+        assert_eq!(err("..#\n.^x\n"), Some("line 2, column 3: unexpected 'x' in map".to_string()));
+        assert_eq!(err("^.#\n.^.\n"), Some("line 2, column 2: found a second guard".to_string()));
+        assert_eq!(err("..#\n...\n"), Some("invalid input: no guard ('^') on the map".to_string()));
+    }
+}
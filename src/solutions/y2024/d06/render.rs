@@ -0,0 +1,204 @@
+// src/solutions/y2024/d06/render.rs
+//! Pictures of the guard's patrol, for checking loop detection by eye: the
+//! route as ASCII in the puzzle's own style, a step-by-step animation for the
+//! terminal, and SVG or PPM images.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use super::{loop_obstacles, Map, Patrol};
+use crate::geometry::Direction4;
+use crate::grid::Grid;
+
+const CELL_SIZE: usize = 10;
+
+const BACKGROUND: [u8; 3] = [15, 15, 35];
+const OBSTACLE: [u8; 3] = [150, 150, 150];
+const PATH: [u8; 3] = [255, 255, 102];
+const START: [u8; 3] = [0, 204, 0];
+const LOOP_OBSTACLE: [u8; 3] = [255, 51, 51];
+
+/// The map with the whole route on it: `|` and `-` where the guard walked
+/// vertically or horizontally, `+` where they did both (which includes every
+/// turn), their starting arrow, and `O` wherever one more obstacle would trap
+/// them in a loop.
+pub fn route(patrol: &Patrol) -> Grid<char> {
+    let walk = patrol.map.simulate_path(patrol.start(), None);
+    let mut canvas = draw(&patrol.map, &walk.seen);
+    canvas[patrol.start_pos] = patrol.start_dir.arrow();
+    for pos in loop_obstacles(&patrol.map, patrol.start_pos, patrol.start_dir) {
+        canvas[pos] = 'O';
+    }
+    canvas
+}
+
+/// Plays the patrol frame by frame, drawing the route so far and the guard
+/// every `steps_per_frame` moves or turns, then finishes on the full
+/// [`route`]. Frames are redrawn in place with ANSI escapes.
+pub fn animate(patrol: &Patrol, out: &mut impl Write, delay: Duration, steps_per_frame: usize) -> io::Result<()> {
+    let map = &patrol.map;
+    let mut seen = Grid::new(map.grid.width(), map.grid.height(), 0u8);
+    let mut loops = false;
+    let mut steps = 0;
+
+    write!(out, "\x1b[2J")?;
+    for state in map.steps(patrol.start(), None) {
+        let dir_bit = 1 << state.dir as u8;
+        if seen[state.pos] & dir_bit != 0 {
+            loops = true;
+            break;
+        }
+        seen[state.pos] |= dir_bit;
+
+        if steps % steps_per_frame.max(1) == 0 {
+            let mut canvas = draw(map, &seen);
+            canvas[state.pos] = state.dir.arrow();
+            write!(out, "\x1b[H{}\nStep {}\x1b[K\n", canvas, steps)?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        steps += 1;
+    }
+
+    let ending = if loops { "is stuck in a loop" } else { "leaves the map" };
+    write!(out, "\x1b[H{}\nThe guard {} after {} steps\x1b[K\n", route(patrol), ending, steps)?;
+    out.flush()
+}
+
+/// The [`route`] as an SVG image, one 10x10 square per cell.
+pub fn to_svg(patrol: &Patrol) -> String {
+    let canvas = route(patrol);
+    let (width, height) = (canvas.width() * CELL_SIZE, canvas.height() * CELL_SIZE);
+    let half = CELL_SIZE / 2;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+        width, height, width, height
+    );
+    let _ = writeln!(svg, "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>", width, height, hex(BACKGROUND));
+    for (pos, &cell) in canvas.iter() {
+        let (x, y) = (pos.col as usize * CELL_SIZE, pos.row as usize * CELL_SIZE);
+        let (cx, cy) = (x + half, y + half);
+        let line = |x1, y1, x2, y2| {
+            format!("<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"2\"/>", x1, y1, x2, y2, hex(PATH))
+        };
+        let element = match cell {
+            '#' => format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>", x, y, CELL_SIZE, CELL_SIZE, hex(OBSTACLE)),
+            '|' => line(cx, y, cx, y + CELL_SIZE),
+            '-' => line(x, cy, x + CELL_SIZE, cy),
+            '+' => line(cx, y, cx, y + CELL_SIZE) + &line(x, cy, x + CELL_SIZE, cy),
+            'O' => format!("<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>", cx, cy, half - 1, hex(LOOP_OBSTACLE)),
+            '.' => continue,
+            _ => format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>", x, y, CELL_SIZE, CELL_SIZE, hex(START)),
+        };
+        let _ = writeln!(svg, "{}", element);
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// The [`route`] as a binary PPM image, `scale` pixels per cell.
+pub fn to_ppm(patrol: &Patrol, scale: usize) -> Vec<u8> {
+    let canvas = route(patrol);
+    let scale = scale.max(1);
+    let (width, height) = (canvas.width() * scale, canvas.height() * scale);
+
+    // Path lines run through the middle of each cell
+    let thickness = (scale / 4).max(1);
+    let band = (scale - thickness) / 2..(scale - thickness) / 2 + thickness;
+
+    let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for y in 0..height {
+        let row = canvas.row(y / scale);
+        for x in 0..width {
+            let (dx, dy) = (x % scale, y % scale);
+            let colour = match row[x / scale] {
+                '#' => OBSTACLE,
+                '|' if band.contains(&dx) => PATH,
+                '-' if band.contains(&dy) => PATH,
+                '+' if band.contains(&dx) || band.contains(&dy) => PATH,
+                'O' => LOOP_OBSTACLE,
+                '.' | '|' | '-' | '+' => BACKGROUND,
+                _ => START,
+            };
+            ppm.extend_from_slice(&colour);
+        }
+    }
+    ppm
+}
+
+/// The map with `|`, `-` and `+` on every cell with a bit set in `seen`.
+fn draw(map: &Map, seen: &Grid<u8>) -> Grid<char> {
+    let vertical = 1 << Direction4::Up as u8 | 1 << Direction4::Down as u8;
+    let horizontal = 1 << Direction4::Left as u8 | 1 << Direction4::Right as u8;
+
+    let mut canvas = map.grid.map(|&cell| if cell == '#' { '#' } else { '.' });
+    for (pos, &dirs) in seen.iter() {
+        match (dirs & vertical != 0, dirs & horizontal != 0) {
+            (true, true) => canvas[pos] = '+',
+            (true, false) => canvas[pos] = '|',
+            (false, true) => canvas[pos] = '-',
+            (false, false) => {}
+        }
+    }
+    canvas
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::solutions::y2024::d06::Day06;
+
+    fn example() -> Patrol {
+        Day06.parse(include_str!("../../../../examples/2024/day06/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(
+            route(&example()).to_string(),
+            "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O^-+-+.
+.+----OO#.
+#O-O--+|..
+......#O..",
+        );
+    }
+
+    #[test]
+    fn test_animate_ends_on_route() {
+        let mut out = Vec::new();
+        animate(&example(), &mut out, Duration::ZERO, 10).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Step 0\x1b[K\n"));
+        assert!(!out.contains("Step 5\x1b[K\n"));
+        assert!(out.ends_with(&format!("{}\nThe guard leaves the map after 55 steps\x1b[K\n", route(&example()))));
+    }
+
+    #[test]
+    fn test_images() {
+        let svg = to_svg(&example());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"100\""));
+        assert_eq!(svg.matches("<circle").count(), 6);
+
+        let ppm = to_ppm(&example(), 4);
+        let header = b"P6\n40 40\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 40 * 40 * 3);
+    }
+}
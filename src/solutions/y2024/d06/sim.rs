@@ -0,0 +1,395 @@
+// src/solutions/y2024/d06/sim.rs
+//! A configurable take on the day 6 patrol for exploring variants of the
+//! puzzle: any number of guards starting at `^`, `>`, `v` or `<`, a choice of
+//! what a blocked guard does and of what happens at the edge of the map, and
+//! obstacles that move.
+//!
+//! Maps use `#` for walls and `U`, `R`, `D` or `L` for a moving obstacle and
+//! the direction it starts in. Each tick every guard still on the map moves
+//! one cell or turns, then every moving obstacle moves one cell, bouncing
+//! back when a wall, another obstacle, a guard or the edge is in its way.
+//! Guards don't block each other.
+//!
+//! [`Simulation::parse`] follows the puzzle's rules by default;
+//! [`Simulation::with_turn`] makes blocked guards turn left or around
+//! instead, and [`Simulation::with_edges`] makes the map wrap around. Step
+//! through it one tick at a time with [`Simulation::step`] or by iterating,
+//! print it to see the current state, or [`Simulation::run`] it to find out
+//! whether every guard leaves or when the whole thing starts repeating:
+//!
+//! ```
+//! use advent_of_code::solutions::y2024::d06::sim::{Edges, Outcome, Simulation};
+//!
+//! let sim = Simulation::parse("..v\n>..\n...\n").unwrap();
+//! assert_eq!(sim.run(), Outcome::Cleared { ticks: 3 });
+//! assert_eq!(sim.with_edges(Edges::Wrap).run(), Outcome::Loops { start: 0, length: 3 });
+//! ```
+
+use std::fmt;
+
+use crate::error::{AocError, Result};
+use crate::geometry::{Direction4, Point};
+use crate::grid::Grid;
+
+/// What a guard does when the next cell is blocked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Turn {
+    /// The puzzle's rule.
+    #[default]
+    Right,
+    Left,
+    Around,
+}
+
+impl Turn {
+    fn apply(self, dir: Direction4) -> Direction4 {
+        match self {
+            Turn::Right => dir.turn_right(),
+            Turn::Left => dir.turn_left(),
+            Turn::Around => dir.reverse(),
+        }
+    }
+}
+
+/// What happens when a guard or moving obstacle reaches the edge of the map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Edges {
+    /// Guards walk off the map and are gone; obstacles bounce back.
+    #[default]
+    Exit,
+    /// The map is a torus: whatever leaves one side comes back on the other.
+    Wrap,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Point,
+    pub dir: Direction4,
+    /// False once the guard has walked off the map.
+    pub on_map: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MovingObstacle {
+    pub pos: Point,
+    pub dir: Direction4,
+}
+
+/// How a simulation ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every guard had walked off the map after this many more ticks.
+    Cleared { ticks: usize },
+    /// From tick `start` on, guards and obstacles repeat the same `length`
+    /// ticks forever.
+    Loops { start: usize, length: usize },
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    walls: Grid<bool>,
+    guards: Vec<Guard>,
+    obstacles: Vec<MovingObstacle>,
+    turn: Turn,
+    edges: Edges,
+    tick: usize,
+    /// Every cell a guard has stood on.
+    visited: Grid<bool>,
+}
+
+impl Simulation {
+    /// Reads a map with at least one guard; guards and moving obstacles are
+    /// numbered row by row.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut guards = Vec::new();
+        let mut obstacles = Vec::new();
+        let cells = Grid::parse_with(input, |ch| match ch {
+            '.' | '#' => Ok(ch),
+            _ if Direction4::from_arrow(ch).is_some() || obstacle_direction(ch).is_some() => Ok(ch),
+            _ => Err(format!("unexpected '{}' in map", ch)),
+        })?;
+
+        for (pos, &ch) in cells.iter() {
+            if let Some(dir) = Direction4::from_arrow(ch) {
+                guards.push(Guard { pos, dir, on_map: true });
+            } else if let Some(dir) = obstacle_direction(ch) {
+                obstacles.push(MovingObstacle { pos, dir });
+            }
+        }
+        if guards.is_empty() {
+            return Err(AocError::input("no guards ('^', '>', 'v' or '<') on the map"));
+        }
+
+        let walls = cells.map(|&ch| ch == '#');
+        let mut visited = walls.map(|_| false);
+        for guard in &guards {
+            visited[guard.pos] = true;
+        }
+        Ok(Simulation { walls, guards, obstacles, turn: Turn::Right, edges: Edges::Exit, tick: 0, visited })
+    }
+
+    pub fn with_turn(mut self, turn: Turn) -> Self {
+        self.turn = turn;
+        self
+    }
+
+    pub fn with_edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    pub fn guards(&self) -> &[Guard] {
+        &self.guards
+    }
+
+    pub fn moving_obstacles(&self) -> &[MovingObstacle] {
+        &self.obstacles
+    }
+
+    /// Ticks simulated so far.
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    /// How many cells any guard has stood on so far.
+    pub fn visited(&self) -> usize {
+        self.visited.iter().filter(|(_, &visited)| visited).count()
+    }
+
+    pub fn is_cleared(&self) -> bool {
+        self.guards.iter().all(|guard| !guard.on_map)
+    }
+
+    /// Advances one tick. Once every guard has left there's nothing more to
+    /// simulate, and this returns false without doing anything.
+    pub fn step(&mut self) -> bool {
+        if self.is_cleared() {
+            return false;
+        }
+
+        for i in 0..self.guards.len() {
+            let guard = self.guards[i];
+            if !guard.on_map {
+                continue;
+            }
+            let next = self.ahead(guard.pos, guard.dir);
+            let guard = &mut self.guards[i];
+            match next {
+                None => guard.on_map = false,
+                Some(next) if self.walls[next] || self.obstacles.iter().any(|o| o.pos == next) => {
+                    guard.dir = self.turn.apply(guard.dir);
+                }
+                Some(next) => {
+                    guard.pos = next;
+                    self.visited[next] = true;
+                }
+            }
+        }
+
+        for i in 0..self.obstacles.len() {
+            let obstacle = self.obstacles[i];
+            let blocked = |next: Point| {
+                self.walls[next]
+                    || self.obstacles.iter().any(|o| o.pos == next)
+                    || self.guards.iter().any(|g| g.on_map && g.pos == next)
+            };
+            match self.ahead(obstacle.pos, obstacle.dir) {
+                Some(next) if !blocked(next) => self.obstacles[i].pos = next,
+                _ => self.obstacles[i].dir = obstacle.dir.reverse(),
+            }
+        }
+
+        self.tick += 1;
+        true
+    }
+
+    /// Simulates until every guard has left or the whole system repeats,
+    /// leaving `self` as it is.
+    ///
+    /// Uses Brent's cycle detection, so it only ever keeps two copies of the
+    /// simulation however long the run.
+    pub fn run(&self) -> Outcome {
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        hare.step();
+        let (mut power, mut length) = (1, 1);
+        loop {
+            if hare.is_cleared() {
+                return Outcome::Cleared { ticks: hare.tick - self.tick };
+            }
+            if hare.same_state(&tortoise) {
+                break;
+            }
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare.step();
+            length += 1;
+        }
+
+        // Walk two copies `length` apart until they meet where the cycle starts
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        for _ in 0..length {
+            hare.step();
+        }
+        while !hare.same_state(&tortoise) {
+            tortoise.step();
+            hare.step();
+        }
+        Outcome::Loops { start: tortoise.tick - self.tick, length }
+    }
+
+    /// The cell one step from `pos`, or `None` off the edge of a map that
+    /// doesn't wrap.
+    fn ahead(&self, pos: Point, dir: Direction4) -> Option<Point> {
+        let next = pos + dir.delta();
+        match self.edges {
+            _ if self.walls.in_bounds(next) => Some(next),
+            Edges::Exit => None,
+            Edges::Wrap => {
+                let (height, width) = (self.walls.height() as isize, self.walls.width() as isize);
+                Some(Point::new(next.row.rem_euclid(height), next.col.rem_euclid(width)))
+            }
+        }
+    }
+
+    /// Whether the two would behave identically from here on; the tick and
+    /// the cells visited so far don't count.
+    fn same_state(&self, other: &Simulation) -> bool {
+        self.guards == other.guards && self.obstacles == other.obstacles
+    }
+}
+
+/// Steps through the simulation, yielding the guards after each tick until
+/// every guard has left (which never happens if they loop).
+impl Iterator for Simulation {
+    type Item = Vec<Guard>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step().then(|| self.guards.clone())
+    }
+}
+
+/// Draws the current state in the map format, guards over obstacles.
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut canvas = self.walls.map(|&wall| if wall { '#' } else { '.' });
+        for obstacle in &self.obstacles {
+            canvas[obstacle.pos] = obstacle_marker(obstacle.dir);
+        }
+        for guard in self.guards.iter().filter(|guard| guard.on_map) {
+            canvas[guard.pos] = guard.dir.arrow();
+        }
+        write!(f, "{}", canvas)
+    }
+}
+
+fn obstacle_direction(ch: char) -> Option<Direction4> {
+    match ch {
+        'U' => Some(Direction4::Up),
+        'R' => Some(Direction4::Right),
+        'D' => Some(Direction4::Down),
+        'L' => Some(Direction4::Left),
+        _ => None,
+    }
+}
+
+fn obstacle_marker(dir: Direction4) -> char {
+    match dir {
+        Direction4::Up => 'U',
+        Direction4::Right => 'R',
+        Direction4::Down => 'D',
+        Direction4::Left => 'L',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::solutions::y2024::d06::Day06;
+
+    #[test]
+    fn test_matches_the_puzzle() {
+        let input = include_str!("../../../../examples/2024/day06/example.txt");
+        let mut sim = Simulation::parse(input).unwrap();
+        assert_eq!(sim.run(), Outcome::Cleared { ticks: 55 });
+        sim.step();
+        assert_eq!(sim.run(), Outcome::Cleared { ticks: 54 });
+
+        sim.by_ref().for_each(drop);
+        assert_eq!(sim.visited(), 41);
+        assert_eq!(Day06.solve_both(input).unwrap().0, 41);
+
+        // With the obstacle from the puzzle's first loop example
+        let blocked = input.replace(".#..^.....", ".#.#^.....");
+        assert_ne!(blocked, input);
+        assert!(matches!(Simulation::parse(&blocked).unwrap().run(), Outcome::Loops { .. }));
+    }
+
+    #[test]
+    fn test_turn_policies() {
+        let corridor = "#\n.\n^\n";
+        let sim = Simulation::parse(corridor).unwrap();
+        // Up a cell, turn right and walk off the side
+        assert_eq!(sim.run(), Outcome::Cleared { ticks: 3 });
+        assert_eq!(sim.clone().with_turn(Turn::Left).run(), Outcome::Cleared { ticks: 3 });
+
+        // Up, turn around, back down and off the bottom
+        let mut around = sim.with_turn(Turn::Around);
+        assert_eq!(around.run(), Outcome::Cleared { ticks: 4 });
+        let steps: Vec<(isize, Direction4, bool)> =
+            around.by_ref().map(|guards| (guards[0].pos.row, guards[0].dir, guards[0].on_map)).collect();
+        assert_eq!(
+            steps,
+            vec![
+                (1, Direction4::Up, true),
+                (1, Direction4::Down, true),
+                (2, Direction4::Down, true),
+                (2, Direction4::Down, false)
+            ]
+        );
+        assert_eq!(around.tick(), 4);
+    }
+
+    #[test]
+    fn test_several_guards_and_wrapping() {
+        let sim = Simulation::parse("..v\n>..\n...\n").unwrap();
+        assert_eq!(sim.guards().len(), 2);
+        assert_eq!(sim.run(), Outcome::Cleared { ticks: 3 });
+
+        let mut wrapped = sim.with_edges(Edges::Wrap);
+        assert_eq!(wrapped.run(), Outcome::Loops { start: 0, length: 3 });
+        wrapped.step();
+        assert_eq!(wrapped.to_string(), "...\n.>v\n...");
+    }
+
+    #[test]
+    fn test_moving_obstacles() {
+        // The obstacle bounces between the walls every 8 ticks while the
+        // guard laps its row every 6
+        let sim = Simulation::parse(">.....\n#R...#\n").unwrap().with_edges(Edges::Wrap);
+        assert_eq!(sim.run(), Outcome::Loops { start: 0, length: 24 });
+
+        // The obstacle moves into the guard's way, so they turn
+        let mut sim = Simulation::parse("R..\n...\n.^.\n").unwrap();
+        sim.step();
+        assert_eq!(sim.to_string(), ".R.\n.^.\n...");
+        sim.step();
+        assert_eq!(sim.to_string(), "..R\n.>.\n...");
+
+        // and it bounces off a guard in its way
+        let mut sim = Simulation::parse("...\nR..\n.^.\n").unwrap();
+        sim.step();
+        assert_eq!(sim.to_string(), "...\nL^.\n...");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Simulation::parse("..\n..\n").unwrap_err().to_string().contains("no guards"));
+        assert!(Simulation::parse(".^\n.x\n").is_err());
+    }
+}
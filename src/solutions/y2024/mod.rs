@@ -0,0 +1,20 @@
+// src/solutions/y2024/mod.rs
+use super::Calendar;
+
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+// Future days will be added here
+
+/// Every implemented 2024 day, in calendar order.
+pub static CALENDAR: Calendar = Calendar::new(2024, &[
+    &d01::Day01,
+    &d02::Day02,
+    &d03::Day03,
+    &d04::Day04,
+    &d05::Day05,
+    &d06::Day06,
+]);
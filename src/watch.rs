@@ -0,0 +1,271 @@
+// src/watch.rs
+//! `--watch`: re-runs one day in-process whenever its input or examples
+//! change, and rebuilds and restarts the binary when a source file changes.
+//! Every run shows the answers next to the previous run's.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use advent_of_code::answer::Answer;
+use advent_of_code::answers::{self, Expected};
+use advent_of_code::examples::{self, EXAMPLES_DIR};
+use advent_of_code::input::{self, FetchPolicy, InputSource};
+use advent_of_code::log::error;
+use advent_of_code::runner::{self, PartResult, RunConfig};
+use advent_of_code::solution::Runner;
+
+use crate::cli::RunOptions;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Carries the last answers across a rebuild and restart, in the answers-file format.
+const PREVIOUS_ENV: &str = "AOC_WATCH_PREVIOUS";
+
+/// Modification times of every watched file that currently exists.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub fn watch(year: u16, solution: &'static dyn Runner, options: &RunOptions) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Resolve this before a rebuild replaces the binary
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            error!("Cannot locate the running binary to restart it: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let day = solution.day();
+    let inputs = watched_inputs(root, year, day, &options.input);
+    let sources = [root.join("src"), root.join("Cargo.toml")];
+    let is_source = |path: &Path| path.extension().is_some_and(|ext| ext == "rs" || ext == "toml");
+
+    let mut previous = env::var(PREVIOUS_ENV)
+        .ok()
+        .and_then(|text| answers::parse_answers(&text).ok())
+        .unwrap_or_default();
+    let mut input_state = snapshot(&inputs, |_| true);
+    let mut source_state = snapshot(&sources, is_source);
+
+    eprintln!("Watching {} day {} input, examples and sources (Ctrl-C to stop)", year, day);
+    loop {
+        previous = run_once(year, solution, options, &previous);
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let sources_now = snapshot(&sources, is_source);
+            if sources_now != source_state {
+                source_state = sources_now;
+                eprintln!("\nSources changed, rebuilding...");
+                if rebuild(root) {
+                    return restart(&exe, &previous);
+                }
+                eprintln!("Build failed, still watching");
+                continue;
+            }
+
+            let inputs_now = snapshot(&inputs, |_| true);
+            if inputs_now != input_state {
+                input_state = inputs_now;
+                eprintln!("\nInput changed, re-running...");
+                break;
+            }
+        }
+    }
+}
+
+/// The day's input file and its examples directory.
+fn watched_inputs(root: &Path, year: u16, day: u8, source: &InputSource) -> Vec<PathBuf> {
+    let input = match source {
+        InputSource::File(path) => path.clone(),
+        _ => input::day_input_path(&input::input_dir(), year, day),
+    };
+    vec![input, examples::day_examples_dir(&root.join(EXAMPLES_DIR), year, day)]
+}
+
+/// Walks the given files and directories, recording when each matching file
+/// was last modified. Missing paths are simply absent, so creating or
+/// deleting a file also counts as a change.
+fn snapshot(paths: &[PathBuf], include: impl Fn(&Path) -> bool) -> Snapshot {
+    let mut files = Snapshot::new();
+    let mut pending: Vec<PathBuf> = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| entry.ok().map(|entry| entry.path())));
+            }
+        } else if include(&path) {
+            if let Ok(modified) = metadata.modified() {
+                files.insert(path, modified);
+            }
+        }
+    }
+    files
+}
+
+/// Checks the examples, then solves the real input and prints each answer
+/// against `previous`. Returns the answers to compare the next run with.
+fn run_once(year: u16, solution: &'static dyn Runner, options: &RunOptions, previous: &Expected) -> Expected {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    println!("Day {}: {}", solution.day(), solution.name());
+
+    match examples::discover(&root.join(EXAMPLES_DIR), year, solution.day()) {
+        Ok(found) => {
+            for example in found {
+                let config = RunConfig {
+                    input: InputSource::File(example.input_path.clone()),
+                    fetch: FetchPolicy::Never,
+                    part: options.part,
+                    check: false,
+                    timeout: options.timeout,
+                };
+                let result = runner::run_day(year, solution, &config);
+                match result.outcome {
+                    Ok(parts) => {
+                        let statuses: Vec<String> = parts
+                            .iter()
+                            .filter(|part| example.expected.part(part.part).is_some())
+                            .map(|part| {
+                                let status = answers::check(&part.answer, example.expected.part(part.part));
+                                format!("part {} {}", part.part, status)
+                            })
+                            .collect();
+                        if !statuses.is_empty() {
+                            println!("Example {}: {}", example.name, statuses.join(", "));
+                        }
+                    }
+                    Err(e) => println!("Example {}: {}", example.name, e),
+                }
+            }
+        }
+        Err(e) => error!("{}", e),
+    }
+
+    let result = runner::run_day(year, solution, &options.run_config());
+    if options.history && options.input == InputSource::Default {
+        crate::record_history(std::slice::from_ref(&result), options.timeout.is_some());
+    }
+    let parts = match result.outcome {
+        Ok(parts) => parts,
+        Err(e) => {
+            error!("{}", e);
+            return previous.clone();
+        }
+    };
+
+    let mut current = previous.clone();
+    for part in &parts {
+        println!("{}", describe(part, previous.part(part.part)));
+        let slot = if part.part == 1 { &mut current.part1 } else { &mut current.part2 };
+        *slot = Some(part.answer.clone());
+    }
+    current
+}
+
+/// `Part 1: 41 (was 40)`, with the check status when `--check` is on.
+fn describe(part: &PartResult, previous: Option<&Answer>) -> String {
+    let status = part.check.as_ref().map_or(String::new(), |status| format!(" [{}]", status));
+    let change = match previous {
+        None => "",
+        Some(previous) if *previous == part.answer => " (unchanged)",
+        Some(_) => " (changed)",
+    };
+
+    match previous {
+        Some(previous) if *previous != part.answer && !previous.is_multiline() && !part.answer.is_multiline() => {
+            format!("Part {}: {}{} (was {})", part.part, part.answer, status, previous)
+        }
+        _ if part.answer.is_multiline() => format!("Part {}:{}{}\n{}", part.part, status, change, part.answer),
+        _ => format!("Part {}: {}{}{}", part.part, part.answer, status, change),
+    }
+}
+
+fn rebuild(root: &Path) -> bool {
+    let mut command = Command::new(option_env!("CARGO").unwrap_or("cargo"));
+    command.arg("build").arg("--manifest-path").arg(root.join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    match command.status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            error!("Could not run cargo: {}", e);
+            false
+        }
+    }
+}
+
+/// Replaces this process with the freshly built binary, passing the same
+/// arguments and the previous answers.
+fn restart(exe: &Path, previous: &Expected) -> ExitCode {
+    let mut command = Command::new(exe);
+    command.args(env::args_os().skip(1)).env(PREVIOUS_ENV, previous.to_string());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let e = command.exec();
+        error!("Could not restart {}: {}", exe.display(), e);
+        ExitCode::FAILURE
+    }
+
+    #[cfg(not(unix))]
+    match command.status() {
+        Ok(status) => ExitCode::from(status.code().unwrap_or(1) as u8),
+        Err(e) => {
+            error!("Could not restart {}: {}", exe.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn part(answer: Answer) -> PartResult {
+        PartResult { part: 1, answer, time: Duration::ZERO, check: None }
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&part(Answer::Int(41)), None), "Part 1: 41");
+        assert_eq!(describe(&part(Answer::Int(41)), Some(&Answer::Int(41))), "Part 1: 41 (unchanged)");
+        assert_eq!(describe(&part(Answer::Int(42)), Some(&Answer::Int(41))), "Part 1: 42 (was 41)");
+
+        let grid = Answer::Grid("#.\n.#".to_string());
+        assert_eq!(describe(&part(grid.clone()), Some(&Answer::Int(1))), "Part 1: (changed)\n#.\n.#");
+        assert_eq!(describe(&part(grid.clone()), Some(&grid)), "Part 1: (unchanged)\n#.\n.#");
+    }
+
+    #[test]
+    fn test_snapshot_sees_new_and_deleted_files() {
+        let temp = TempDir::new("watch");
+        let dir = temp.to_path_buf();
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.rs"), "").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let is_rust = |path: &Path| path.extension().is_some_and(|ext| ext == "rs");
+        let before = snapshot(std::slice::from_ref(&dir), is_rust);
+        assert_eq!(before.len(), 1);
+
+        fs::write(dir.join("nested/b.rs"), "").unwrap();
+        let after = snapshot(std::slice::from_ref(&dir), is_rust);
+        assert_eq!(after.len(), 2);
+        assert_ne!(before, after);
+
+        fs::remove_file(dir.join("nested/b.rs")).unwrap();
+        assert_eq!(snapshot(std::slice::from_ref(&dir), is_rust), before);
+    }
+}
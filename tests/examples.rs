@@ -0,0 +1,55 @@
+// tests/examples.rs
+//! Runs every registered day against all of its examples through the public
+//! library API, the same way an outside tool would.
+
+use std::fs;
+use std::path::Path;
+
+use advent_of_code::examples::{self, EXAMPLES_DIR};
+use advent_of_code::solutions;
+
+#[test]
+fn test_registered_examples() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+    let mut failures = Vec::new();
+
+    for (year, solution) in solutions::calendars().iter().flat_map(|c| c.all().iter().map(|s| (c.year(), s))) {
+        // Problems with one day are collected rather than panicking, so every
+        // other day still gets checked
+        let examples = match examples::discover(&examples_dir, year, solution.day()) {
+            Ok(examples) if examples.is_empty() => {
+                failures.push(format!("{} day {} has no examples with expected answers", year, solution.day()));
+                continue;
+            }
+            Ok(examples) => examples,
+            Err(e) => {
+                failures.push(format!("{} day {}: {}", year, solution.day(), e));
+                continue;
+            }
+        };
+
+        for example in examples {
+            let input = fs::read_to_string(&example.input_path).unwrap();
+            let parsed = match solution.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    failures.push(format!("{} day {} {}: {}", year, solution.day(), example.name, e));
+                    continue;
+                }
+            };
+            for part in 1..=2 {
+                if let Some(expected) = example.expected.part(part) {
+                    let answer = solution.part(parsed.as_ref(), part);
+                    if answer != *expected {
+                        failures.push(format!(
+                            "{} day {} {} part {}: got {}, expected {}",
+                            year, solution.day(), example.name, part, answer, expected
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}